@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::collectors::retry::{self, RetryConfig};
+use crate::collectors::{CollectedJob, JobCollector};
+use crate::error::AppError;
+
+pub struct Ashby;
+
+#[async_trait]
+impl JobCollector for Ashby {
+    fn name(&self) -> &str {
+        "ashby"
+    }
+
+    async fn collect(&self, config: &Value) -> Result<Vec<CollectedJob>, AppError> {
+        let careers_url = super::careers_url(config)?;
+        let board_token = super::board_token(careers_url)?;
+        let company_name = super::company_name(config, board_token);
+
+        let client = reqwest::Client::new();
+        let url = format!("https://api.ashbyhq.com/posting-api/job-board/{board_token}");
+
+        let resp = retry::fetch_with_retry(RetryConfig::default(), || async {
+            client
+                .get(&url)
+                .send()
+                .await
+                .map_err(|e| AppError::Internal(format!("Ashby request failed: {e}")))
+        })
+        .await?;
+
+        if !resp.status().is_success() {
+            return Err(AppError::Internal(format!("Ashby returned {}", resp.status())));
+        }
+
+        let data: Value = resp
+            .json()
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to parse response: {e}")))?;
+
+        parse_results(&data, &company_name)
+    }
+}
+
+/// A missing `jobs` array means the job board slug doesn't exist or the
+/// response shape changed underneath us, not a transient hiccup.
+fn parse_results(data: &Value, company_name: &str) -> Result<Vec<CollectedJob>, AppError> {
+    let jobs = data.get("jobs").and_then(|v| v.as_array()).ok_or_else(|| {
+        AppError::PermanentCollectorError("Missing 'jobs' in Ashby response".to_string())
+    })?;
+
+    Ok(jobs
+        .iter()
+        .filter_map(|raw| parse_job(raw, company_name))
+        .collect())
+}
+
+fn parse_job(raw: &Value, company_name: &str) -> Option<CollectedJob> {
+    let source_id = raw.get("id")?.as_str()?.to_string();
+    let title = raw.get("title")?.as_str()?.to_string();
+
+    let location = raw
+        .get("location")
+        .and_then(|v| v.as_str())
+        .map(String::from);
+
+    let remote_type = raw
+        .get("isRemote")
+        .and_then(|v| v.as_bool())
+        .map(|remote| if remote { "Remote" } else { "Onsite" }.to_string());
+
+    let url = raw.get("jobUrl").and_then(|v| v.as_str()).map(String::from);
+
+    let description = raw
+        .get("descriptionHtml")
+        .and_then(|v| v.as_str())
+        .map(String::from);
+
+    Some(CollectedJob {
+        company_name: company_name.to_string(),
+        title,
+        url,
+        location,
+        remote_type,
+        salary_min: None,
+        salary_max: None,
+        salary_currency: None,
+        description,
+        source: "ashby".to_string(),
+        source_id,
+        raw_data: Some(raw.clone()),
+    })
+}
@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::collectors::retry::{self, RetryConfig};
+use crate::collectors::{CollectedJob, JobCollector};
+use crate::error::AppError;
+
+pub struct Greenhouse;
+
+#[async_trait]
+impl JobCollector for Greenhouse {
+    fn name(&self) -> &str {
+        "greenhouse"
+    }
+
+    async fn collect(&self, config: &Value) -> Result<Vec<CollectedJob>, AppError> {
+        let careers_url = super::careers_url(config)?;
+        let board_token = super::board_token(careers_url)?;
+        let company_name = super::company_name(config, board_token);
+
+        let client = reqwest::Client::new();
+        let url = format!("https://boards-api.greenhouse.io/v1/boards/{board_token}/jobs?content=true");
+
+        let resp = retry::fetch_with_retry(RetryConfig::default(), || async {
+            client
+                .get(&url)
+                .send()
+                .await
+                .map_err(|e| AppError::Internal(format!("Greenhouse request failed: {e}")))
+        })
+        .await?;
+
+        if !resp.status().is_success() {
+            return Err(AppError::Internal(format!(
+                "Greenhouse returned {}",
+                resp.status()
+            )));
+        }
+
+        let data: Value = resp
+            .json()
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to parse response: {e}")))?;
+
+        parse_results(&data, &company_name)
+    }
+}
+
+/// A missing `jobs` array means the board token doesn't exist or the
+/// response shape changed underneath us, not a transient hiccup - retrying
+/// the same request would fail identically.
+fn parse_results(data: &Value, company_name: &str) -> Result<Vec<CollectedJob>, AppError> {
+    let jobs = data.get("jobs").and_then(|v| v.as_array()).ok_or_else(|| {
+        AppError::PermanentCollectorError("Missing 'jobs' in Greenhouse response".to_string())
+    })?;
+
+    Ok(jobs
+        .iter()
+        .filter_map(|raw| parse_job(raw, company_name))
+        .collect())
+}
+
+fn parse_job(raw: &Value, company_name: &str) -> Option<CollectedJob> {
+    let source_id = raw.get("id")?.as_i64()?.to_string();
+    let title = raw.get("title")?.as_str()?.to_string();
+
+    let location = raw
+        .get("location")
+        .and_then(|v| v.get("name"))
+        .and_then(|v| v.as_str())
+        .map(String::from);
+
+    let url = raw
+        .get("absolute_url")
+        .and_then(|v| v.as_str())
+        .map(String::from);
+
+    let description = raw
+        .get("content")
+        .and_then(|v| v.as_str())
+        .map(String::from);
+
+    Some(CollectedJob {
+        company_name: company_name.to_string(),
+        title,
+        url,
+        location,
+        remote_type: None,
+        salary_min: None,
+        salary_max: None,
+        salary_currency: None,
+        description,
+        source: "greenhouse".to_string(),
+        source_id,
+        raw_data: Some(raw.clone()),
+    })
+}
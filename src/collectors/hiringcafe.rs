@@ -4,6 +4,10 @@ use base64::engine::general_purpose::STANDARD as BASE64;
 use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
 use serde_json::Value;
 
+use std::time::Duration;
+
+use crate::collectors::impersonate::{self, ImpersonationProfile};
+use crate::collectors::retry::{self, RetryConfig};
 use crate::collectors::{CollectedJob, JobCollector};
 use crate::error::AppError;
 
@@ -31,6 +35,10 @@ impl JobCollector for HiringCafe {
         "hiringcafe"
     }
 
+    fn impersonation_profile(&self) -> ImpersonationProfile {
+        ImpersonationProfile::Chrome131
+    }
+
     async fn collect(&self, config: &Value) -> Result<Vec<CollectedJob>, AppError> {
         let query = config
             .get("jobTitleQuery")
@@ -44,52 +52,65 @@ impl JobCollector for HiringCafe {
             })
             .unwrap_or("");
 
-        // Try native reqwest first, fall back to Python CLI on 429
-        match self.collect_native(config, query).await {
-            Ok(jobs) => Ok(jobs),
-            Err(e) => {
-                let msg = e.to_string();
-                if msg.contains("429") || msg.contains("Too Many Requests") {
-                    tracing::warn!("HiringCafe returned 429, falling back to Python CLI");
-                    self.collect_via_cli(query).await
-                } else {
-                    Err(e)
+        let mut profile = self.impersonation_profile();
+        let mut last_err = None;
+
+        // A handful of Chrome versions to rotate through if the site keeps
+        // rejecting us; this caps it at one retry per known profile rather
+        // than looping forever.
+        for _ in 0..3 {
+            match self.collect_native(config, query, profile).await {
+                Ok(jobs) => return Ok(jobs),
+                Err(e) => {
+                    let msg = e.to_string();
+                    if msg.contains("429") || msg.contains("Too Many Requests") {
+                        tracing::warn!(?profile, "HiringCafe returned 429, rotating TLS profile");
+                        profile = profile.rotate();
+                        last_err = Some(e);
+                    } else {
+                        return Err(e);
+                    }
                 }
             }
         }
+
+        Err(last_err.unwrap_or_else(|| AppError::Internal("HiringCafe request failed".into())))
     }
 }
 
 impl HiringCafe {
-    /// Fetch jobs using reqwest with browser-like headers.
+    /// Fetch jobs using a client that impersonates the given browser profile
+    /// at the TLS/HTTP layer, not just via a `User-Agent` header.
     async fn collect_native(
         &self,
         config: &Value,
         query: &str,
+        profile: ImpersonationProfile,
     ) -> Result<Vec<CollectedJob>, AppError> {
         let state = build_state(config, query);
         let encoded = encode_state(&state);
 
-        let client = reqwest::Client::builder()
-            .user_agent("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Safari/537.36")
-            .build()
-            .map_err(|e| AppError::Internal(format!("Failed to build HTTP client: {e}")))?;
+        let client = impersonate::build_client(profile)?;
 
         let url = format!(
             "{BASE_URL}/api/search-jobs?s={}&size={PAGE_SIZE}&page=0",
             urlencoded(&encoded)
         );
 
-        let resp = client
-            .get(&url)
-            .header("Accept", "application/json,text/html,*/*;q=0.8")
-            .header("Accept-Language", "de-DE,de;q=0.9,en;q=0.8")
-            .header("Sec-Fetch-Dest", "document")
-            .header("Sec-Fetch-Mode", "navigate")
-            .header("Sec-Fetch-Site", "none")
-            .send()
-            .await
-            .map_err(|e| AppError::Internal(format!("HiringCafe request failed: {e}")))?;
+        let retry_config = retry_config_from(config);
+        let resp = retry::fetch_with_retry(retry_config, || async {
+            client
+                .get(&url)
+                .header("Accept", "application/json,text/html,*/*;q=0.8")
+                .header("Accept-Language", "de-DE,de;q=0.9,en;q=0.8")
+                .header("Sec-Fetch-Dest", "document")
+                .header("Sec-Fetch-Mode", "navigate")
+                .header("Sec-Fetch-Site", "none")
+                .send()
+                .await
+                .map_err(|e| AppError::Internal(format!("HiringCafe request failed: {e}")))
+        })
+        .await?;
 
         if resp.status().as_u16() == 429 {
             return Err(AppError::Internal("429 Too Many Requests".to_string()));
@@ -109,38 +130,24 @@ impl HiringCafe {
 
         parse_results(&data)
     }
+}
 
-    /// Fallback: shell out to the Python CLI which uses curl_cffi for TLS fingerprinting.
-    async fn collect_via_cli(&self, query: &str) -> Result<Vec<CollectedJob>, AppError> {
-        let output = tokio::process::Command::new("hiringcafe-cli")
-            .args(["search", query, "--llm", "--count", "40"])
-            .output()
-            .await
-            .map_err(|e| AppError::Internal(format!("Failed to run hiringcafe-cli: {e}")))?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(AppError::Internal(format!(
-                "hiringcafe-cli failed: {stderr}"
-            )));
-        }
-
-        let data: Value = serde_json::from_slice(&output.stdout)
-            .map_err(|e| AppError::Internal(format!("Failed to parse CLI output: {e}")))?;
-
-        let jobs = data
-            .get("jobs")
-            .and_then(|v| v.as_array())
-            .cloned()
-            .unwrap_or_default();
-
-        let mut collected = Vec::new();
-        for job in &jobs {
-            if let Some(cj) = parse_cli_job(job) {
-                collected.push(cj);
-            }
-        }
-        Ok(collected)
+/// Read retry tuning from the collector's config, falling back to
+/// `RetryConfig::default()`. Lets a particular deployment dial a source
+/// down (or up) without a code change: `{"retryMaxAttempts": 6, "retryBaseDelayMs": 1000}`.
+fn retry_config_from(config: &Value) -> RetryConfig {
+    let default = RetryConfig::default();
+    RetryConfig {
+        max_attempts: config
+            .get("retryMaxAttempts")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32)
+            .unwrap_or(default.max_attempts),
+        base_delay: config
+            .get("retryBaseDelayMs")
+            .and_then(|v| v.as_u64())
+            .map(Duration::from_millis)
+            .unwrap_or(default.base_delay),
     }
 }
 
@@ -294,10 +301,13 @@ fn urlencoded(s: &str) -> String {
 
 /// Parse the native API search response into CollectedJob structs.
 fn parse_results(data: &Value) -> Result<Vec<CollectedJob>, AppError> {
-    let results = data
-        .get("results")
-        .and_then(|v| v.as_array())
-        .ok_or_else(|| AppError::Internal("Missing 'results' in response".to_string()))?;
+    // A missing `results` field means the API's response shape changed
+    // underneath us, not a transient hiccup - retrying the same response
+    // would fail identically, so this is a permanent error rather than one
+    // that should burn a retry attempt.
+    let results = data.get("results").and_then(|v| v.as_array()).ok_or_else(|| {
+        AppError::PermanentCollectorError("Missing 'results' in response".to_string())
+    })?;
 
     let mut jobs = Vec::new();
     for raw in results {
@@ -376,49 +386,6 @@ fn parse_api_job(raw: &Value) -> Option<CollectedJob> {
     })
 }
 
-/// Parse a job from the Python CLI's --llm JSON output format.
-fn parse_cli_job(job: &Value) -> Option<CollectedJob> {
-    let title = job.get("title").and_then(|v| v.as_str())?.to_string();
-    let company_name = job
-        .get("company")
-        .and_then(|v| v.as_str())
-        .unwrap_or("Unknown")
-        .to_string();
-
-    let source_id = job
-        .get("id")
-        .or_else(|| job.get("requisition_id"))
-        .and_then(|v| v.as_str())?
-        .to_string();
-
-    Some(CollectedJob {
-        company_name,
-        title,
-        url: job
-            .get("apply_url")
-            .and_then(|v| v.as_str())
-            .map(String::from),
-        location: job
-            .get("location")
-            .and_then(|v| v.as_str())
-            .map(String::from),
-        remote_type: job
-            .get("workplace_type")
-            .and_then(|v| v.as_str())
-            .map(String::from),
-        salary_min: None,
-        salary_max: None,
-        salary_currency: None,
-        description: job
-            .get("description_html")
-            .and_then(|v| v.as_str())
-            .map(String::from),
-        source: "hiringcafe".to_string(),
-        source_id,
-        raw_data: Some(job.clone()),
-    })
-}
-
 /// Extract yearly salary min/max from v5_processed_job_data.
 fn extract_salary(vpd: &Value) -> (Option<i32>, Option<i32>) {
     let min = vpd
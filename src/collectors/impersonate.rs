@@ -0,0 +1,51 @@
+use reqwest::Client;
+use reqwest_impersonate::{Impersonate, ClientBuilder as ImpersonateClientBuilder};
+
+use crate::error::AppError;
+
+/// A browser profile to impersonate at the TLS/HTTP layer (cipher order,
+/// extensions, ALPN), not just the `User-Agent` header. Some sites key
+/// bot detection off the former, which a plain `reqwest` client can't
+/// reproduce on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImpersonationProfile {
+    Chrome131,
+    Chrome120,
+    Chrome110,
+}
+
+impl ImpersonationProfile {
+    fn as_impersonate(self) -> Impersonate {
+        match self {
+            ImpersonationProfile::Chrome131 => Impersonate::Chrome131,
+            ImpersonationProfile::Chrome120 => Impersonate::Chrome120,
+            ImpersonationProfile::Chrome110 => Impersonate::Chrome110,
+        }
+    }
+
+    /// The next profile to try after this one gets blocked, so a retry
+    /// loop doesn't keep hammering the site with the same fingerprint.
+    pub fn rotate(self) -> ImpersonationProfile {
+        match self {
+            ImpersonationProfile::Chrome131 => ImpersonationProfile::Chrome120,
+            ImpersonationProfile::Chrome120 => ImpersonationProfile::Chrome110,
+            ImpersonationProfile::Chrome110 => ImpersonationProfile::Chrome131,
+        }
+    }
+}
+
+impl Default for ImpersonationProfile {
+    fn default() -> Self {
+        ImpersonationProfile::Chrome131
+    }
+}
+
+/// Build a client whose TLS ClientHello and HTTP/2 settings match a real
+/// instance of the given Chrome version, instead of just setting a
+/// `User-Agent` header on a generic reqwest client.
+pub fn build_client(profile: ImpersonationProfile) -> Result<Client, AppError> {
+    ImpersonateClientBuilder::new()
+        .impersonate(profile.as_impersonate())
+        .build()
+        .map_err(|e| AppError::Internal(format!("Failed to build impersonated client: {e}")))
+}
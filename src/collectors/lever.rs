@@ -0,0 +1,101 @@
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::collectors::retry::{self, RetryConfig};
+use crate::collectors::{CollectedJob, JobCollector};
+use crate::error::AppError;
+
+pub struct Lever;
+
+#[async_trait]
+impl JobCollector for Lever {
+    fn name(&self) -> &str {
+        "lever"
+    }
+
+    async fn collect(&self, config: &Value) -> Result<Vec<CollectedJob>, AppError> {
+        let careers_url = super::careers_url(config)?;
+        let board_token = super::board_token(careers_url)?;
+        let company_name = super::company_name(config, board_token);
+
+        let client = reqwest::Client::new();
+        let url = format!("https://api.lever.co/v0/postings/{board_token}?mode=json");
+
+        let resp = retry::fetch_with_retry(RetryConfig::default(), || async {
+            client
+                .get(&url)
+                .send()
+                .await
+                .map_err(|e| AppError::Internal(format!("Lever request failed: {e}")))
+        })
+        .await?;
+
+        if !resp.status().is_success() {
+            return Err(AppError::Internal(format!(
+                "Lever returned {}",
+                resp.status()
+            )));
+        }
+
+        let data: Value = resp
+            .json()
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to parse response: {e}")))?;
+
+        parse_results(&data, &company_name)
+    }
+}
+
+/// Lever's postings endpoint returns a bare JSON array rather than an
+/// envelope; anything else means the site isn't what we expect (a wrong
+/// board token, or the API shape changed), so this is permanent.
+fn parse_results(data: &Value, company_name: &str) -> Result<Vec<CollectedJob>, AppError> {
+    let postings = data.as_array().ok_or_else(|| {
+        AppError::PermanentCollectorError("Expected a JSON array from Lever".to_string())
+    })?;
+
+    Ok(postings
+        .iter()
+        .filter_map(|raw| parse_posting(raw, company_name))
+        .collect())
+}
+
+fn parse_posting(raw: &Value, company_name: &str) -> Option<CollectedJob> {
+    let source_id = raw.get("id")?.as_str()?.to_string();
+    let title = raw.get("text")?.as_str()?.to_string();
+
+    let categories = raw.get("categories");
+    let location = categories
+        .and_then(|c| c.get("location"))
+        .and_then(|v| v.as_str())
+        .map(String::from);
+    let remote_type = categories
+        .and_then(|c| c.get("commitment"))
+        .and_then(|v| v.as_str())
+        .map(String::from);
+
+    let url = raw
+        .get("hostedUrl")
+        .and_then(|v| v.as_str())
+        .map(String::from);
+
+    let description = raw
+        .get("descriptionPlain")
+        .and_then(|v| v.as_str())
+        .map(String::from);
+
+    Some(CollectedJob {
+        company_name: company_name.to_string(),
+        title,
+        url,
+        location,
+        remote_type,
+        salary_min: None,
+        salary_max: None,
+        salary_currency: None,
+        description,
+        source: "lever".to_string(),
+        source_id,
+        raw_data: Some(raw.clone()),
+    })
+}
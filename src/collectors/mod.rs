@@ -1,10 +1,16 @@
+pub mod ashby;
+pub mod greenhouse;
 pub mod hiringcafe;
+pub mod impersonate;
+pub mod lever;
+pub mod retry;
 pub mod runner;
 
 use async_trait::async_trait;
 use serde::Serialize;
 
 use crate::error::AppError;
+use crate::collectors::impersonate::ImpersonationProfile;
 
 /// A job as collected from an external source.
 /// Contains company_name (not company_id) since collectors don't know internal IDs.
@@ -34,14 +40,102 @@ pub trait JobCollector: Send + Sync {
     /// Human-readable name matching the collectors table entry.
     fn name(&self) -> &str;
 
+    /// The browser TLS/HTTP fingerprint this collector's HTTP client should
+    /// present. Defaults to the most common current Chrome release; override
+    /// for sources that specifically block that profile.
+    fn impersonation_profile(&self) -> ImpersonationProfile {
+        ImpersonationProfile::default()
+    }
+
     /// Fetch jobs from the external source using the provided JSONB config.
     async fn collect(&self, config: &serde_json::Value) -> Result<Vec<CollectedJob>, AppError>;
 }
 
+/// Whether a collector's jobs span a single global source, or a single
+/// company's board within a source name shared by many companies. Governs
+/// what `DelistScope` is safe to reconcile its results with - see that
+/// type's doc in `models::job` for why conflating the two silently deletes
+/// another company's open postings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollectorScope {
+    /// The collector's `source` belongs entirely to it (e.g. `hiringcafe`'s
+    /// own global search), so `DelistScope::Source` is safe.
+    Global,
+    /// The collector's `source` (e.g. `greenhouse`) is an ATS platform name
+    /// shared across many companies' independent boards - only
+    /// `DelistScope::Company` is safe.
+    PerCompany,
+}
+
+/// All known collectors, keyed by name. `hiringcafe` is a broad, standalone
+/// search collector configured on the `collectors` table; the ATS
+/// collectors (`greenhouse`, `lever`, `ashby`) are company-scoped and are
+/// normally looked up by `Company::ats_platform` instead (see
+/// `runner::collect_company`), but are registered here too so the table
+/// stays the single place that knows every `JobCollector` impl that exists.
+type CollectorFactory = fn() -> Box<dyn JobCollector>;
+
+const REGISTRY: &[(&str, CollectorFactory, CollectorScope)] = &[
+    ("hiringcafe", || Box::new(hiringcafe::HiringCafe), CollectorScope::Global),
+    ("greenhouse", || Box::new(greenhouse::Greenhouse), CollectorScope::PerCompany),
+    ("lever", || Box::new(lever::Lever), CollectorScope::PerCompany),
+    ("ashby", || Box::new(ashby::Ashby), CollectorScope::PerCompany),
+];
+
 /// Look up a collector implementation by name.
 pub fn get_collector(name: &str) -> Option<Box<dyn JobCollector>> {
-    match name {
-        "hiringcafe" => Some(Box::new(hiringcafe::HiringCafe)),
-        _ => None,
-    }
+    REGISTRY
+        .iter()
+        .find(|(registered_name, _, _)| *registered_name == name)
+        .map(|(_, factory, _)| factory())
+}
+
+/// Look up a registered collector's `CollectorScope` by name, if it's in
+/// `REGISTRY` at all - a `company:{id}:{platform}` synthetic name used by
+/// `collect_company` never is, since it isn't a real collector, just a
+/// per-company audit trail.
+pub fn scope_of(name: &str) -> Option<CollectorScope> {
+    REGISTRY
+        .iter()
+        .find(|(registered_name, _, _)| *registered_name == name)
+        .map(|(_, _, scope)| *scope)
+}
+
+/// Pull `careers_url` out of an ATS collector's config. Unlike `hiringcafe`,
+/// which reads a broad search config from the `collectors` table, ATS
+/// collectors are company-scoped - the runner builds this config per
+/// company from `Company::careers_url` rather than a stored row.
+pub(crate) fn careers_url(config: &serde_json::Value) -> Result<&str, AppError> {
+    config
+        .get("careers_url")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| {
+            AppError::PermanentCollectorError("Missing 'careers_url' in config".to_string())
+        })
+}
+
+/// The board/org token most ATS public APIs key on is just the last path
+/// segment of the public careers URL, e.g.
+/// `https://boards.greenhouse.io/acme` -> `acme`.
+pub(crate) fn board_token(careers_url: &str) -> Result<&str, AppError> {
+    careers_url
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .filter(|segment| !segment.is_empty())
+        .ok_or_else(|| {
+            AppError::PermanentCollectorError(format!(
+                "Could not derive a board token from '{careers_url}'"
+            ))
+        })
+}
+
+/// The company name to tag collected jobs with, preferring the one the
+/// runner passed through in config over the bare board token.
+pub(crate) fn company_name(config: &serde_json::Value, fallback: &str) -> String {
+    config
+        .get("company_name")
+        .and_then(|v| v.as_str())
+        .unwrap_or(fallback)
+        .to_string()
 }
@@ -0,0 +1,84 @@
+use std::future::Future;
+use std::time::{Duration, SystemTime};
+
+use rand::Rng;
+use reqwest::Response;
+
+use crate::error::AppError;
+
+/// Statuses that usually mean "the server is overloaded or rate-limiting
+/// you, try again later" rather than "this request is wrong".
+const RETRYABLE_STATUSES: [u16; 5] = [429, 500, 502, 503, 504];
+
+#[derive(Debug, Clone, Copy)]
+pub struct RetryConfig {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        RetryConfig {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Run `fetch` up to `config.max_attempts` times, retrying on a retryable
+/// HTTP status (429, 500, 502, 503 or 504). Honors the response's `Retry-After` header
+/// (either delta-seconds or an HTTP-date) when present, and otherwise
+/// backs off exponentially with jitter so a source that's merely slow
+/// doesn't get hammered by several collectors retrying in lockstep.
+///
+/// Returns the final response regardless of its status once retries are
+/// exhausted (or immediately, if it isn't retryable) — callers still do
+/// their own status handling on the result.
+pub async fn fetch_with_retry<F, Fut>(
+    config: RetryConfig,
+    fetch: F,
+) -> Result<Response, AppError>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<Response, AppError>>,
+{
+    let mut attempt = 0u32;
+    loop {
+        attempt += 1;
+        let resp = fetch().await?;
+
+        if attempt >= config.max_attempts || !RETRYABLE_STATUSES.contains(&resp.status().as_u16())
+        {
+            return Ok(resp);
+        }
+
+        let delay = retry_after(&resp).unwrap_or_else(|| backoff_with_jitter(config.base_delay, attempt));
+        tracing::warn!(
+            status = resp.status().as_u16(),
+            attempt,
+            ?delay,
+            "retryable HTTP status, backing off"
+        );
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// Parse a `Retry-After` header in either of its two allowed forms:
+/// delta-seconds ("120") or an HTTP-date ("Wed, 21 Oct 2015 07:28:00 GMT").
+fn retry_after(resp: &Response) -> Option<Duration> {
+    let value = resp.headers().get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    httpdate::parse_http_date(value)
+        .ok()
+        .and_then(|date| date.duration_since(SystemTime::now()).ok())
+}
+
+fn backoff_with_jitter(base: Duration, attempt: u32) -> Duration {
+    let exp = base.saturating_mul(1u32 << (attempt - 1).min(10));
+    let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..=exp.as_millis() as u64 / 2 + 1));
+    exp + jitter
+}
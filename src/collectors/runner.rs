@@ -1,16 +1,40 @@
-use std::time::Duration;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 use sqlx::PgPool;
 
 use crate::collectors::{CollectedJob, get_collector};
+use crate::error::AppError;
 use crate::models::collector::Collector;
-use crate::models::collector_run::CollectorRun;
+use crate::models::collector_run::{CollectorRun, RunKind};
 use crate::models::company::Company;
-use crate::models::job::{CreateJob, Job};
+use crate::models::event::{CreateEvent, Event, event_type};
+use crate::models::job::{CreateJob, DelistScope, Job};
+use crate::notifier::{NotifyEvent, notifiers_from_config, notify_all};
+use crate::poll_timer::{WithPollTimer, WithStageTimer};
+
+/// Collector fetches slower than this log a `WARN` naming the collector and
+/// run id, surfacing a hung upstream HTTP call without a full metrics backend.
+const SLOW_COLLECT_THRESHOLD: Duration = Duration::from_secs(5);
+/// Per-job upsert/company-resolution calls slower than this log the same way.
+const SLOW_UPSERT_THRESHOLD: Duration = Duration::from_secs(1);
 
 /// Main worker loop: poll for pending runs and process them.
-/// Recovers stale runs on startup and exits gracefully on SIGTERM/SIGINT.
-pub async fn run(pool: PgPool, collector_name: &str, poll_interval: u64) -> anyhow::Result<()> {
+/// Exits gracefully on SIGTERM/SIGINT. Each tick also reclaims runs whose
+/// heartbeat has gone stale (a crashed peer's run, safe to do continuously
+/// since it only touches runs a live worker isn't actively heartbeating)
+/// and reaps runs that have been "running" for longer than `run_timeout`,
+/// marking them `timed_out` rather than leaving them wedged forever. Also
+/// logs a `WARN` if a single claim-and-execute cycle exceeds
+/// `slow_run_warn_secs`, so an operator watching logs notices a collector
+/// slowing down well before it hits `run_timeout_secs`.
+pub async fn run(
+    pool: PgPool,
+    collector_name: &str,
+    poll_interval: u64,
+    run_timeout_secs: u64,
+    slow_run_warn_secs: u64,
+) -> anyhow::Result<()> {
     let collector_impl = get_collector(collector_name)
         .ok_or_else(|| anyhow::anyhow!("Unknown collector: {collector_name}"))?;
 
@@ -19,11 +43,20 @@ pub async fn run(pool: PgPool, collector_name: &str, poll_interval: u64) -> anyh
         anyhow::bail!("Collector '{collector_name}' is disabled");
     }
 
-    // Recover any runs left in "running" state from a previous crash
-    let stale = CollectorRun::recover_stale(&pool, collector_name).await?;
-    if stale > 0 {
-        tracing::warn!("Recovered {stale} stale 'running' runs for '{collector_name}'");
-    }
+    let run_timeout = Duration::from_secs(run_timeout_secs);
+
+    // Heartbeat cadence is fixed, not derived from poll_interval: recover_stale
+    // reclaims by collector_name alone, with no idea which process is
+    // heartbeating a given run, so every producer of runs under this name
+    // (CLI workers, the scheduler, company-scoped ATS collects) must agree on
+    // one cadence. A worker started with a short --poll-interval would
+    // otherwise get a short lease and reclaim a healthy peer's run mid-flight.
+    let heartbeat_interval_secs = DEFAULT_HEARTBEAT_INTERVAL_SECS;
+
+    // A peer is only considered crashed once its heartbeat is this stale -
+    // 3x the shared interval gives it headroom for an occasionally-slow tick
+    // without being mistaken for dead.
+    let lease_timeout = Duration::from_secs(heartbeat_interval_secs * 3);
 
     tracing::info!(
         "Worker started for collector '{collector_name}', polling every {poll_interval}s"
@@ -37,9 +70,26 @@ pub async fn run(pool: PgPool, collector_name: &str, poll_interval: u64) -> anyh
                 break;
             }
             result = async {
+                let stale = CollectorRun::recover_stale(&pool, collector_name, lease_timeout).await?;
+                if stale > 0 {
+                    tracing::warn!("Recovered {stale} stale 'running' run(s) for '{collector_name}'");
+                }
+                let timed_out =
+                    CollectorRun::reap_timed_out(&pool, run_timeout, lease_timeout).await?;
+                if timed_out > 0 {
+                    tracing::warn!("Reaped {timed_out} run(s) stuck in 'running' past {run_timeout_secs}s");
+                }
                 if let Some(run) = CollectorRun::claim_next(&pool, collector_name).await? {
                     tracing::info!("Claimed run {} for '{collector_name}'", run.id);
-                    process_run(&pool, &*collector_impl, &run).await;
+                    let started = Instant::now();
+                    process_run(&pool, &*collector_impl, &run, heartbeat_interval_secs).await;
+                    let elapsed = started.elapsed();
+                    if elapsed > Duration::from_secs(slow_run_warn_secs) {
+                        tracing::warn!(
+                            "Run {} for '{collector_name}' took {elapsed:?}, exceeding the {slow_run_warn_secs}s slow-run threshold",
+                            run.id
+                        );
+                    }
                 }
                 tokio::time::sleep(Duration::from_secs(poll_interval)).await;
                 Ok::<(), anyhow::Error>(())
@@ -52,43 +102,255 @@ pub async fn run(pool: PgPool, collector_name: &str, poll_interval: u64) -> anyh
     Ok(())
 }
 
-async fn process_run(pool: &PgPool, collector: &dyn super::JobCollector, run: &CollectorRun) {
+/// Drain the durable run queue across *all* enabled collectors, instead of
+/// pinning to one via `--collector` like `run` does. `trigger_run` only
+/// enqueues a pending run and expects something to execute it
+/// asynchronously, and a retried `mark_failed` run has nowhere to go back
+/// to but the queue - without this, both sit `pending` forever in a `serve`
+/// process that isn't also running a dedicated `collect` worker for that
+/// collector. Spawned unconditionally from `serve` alongside the scheduler,
+/// since manual/API-triggered runs should work whether or not scheduled
+/// collection is enabled.
+pub async fn run_queue(pool: PgPool, poll_interval: u64, run_timeout_secs: u64, slow_run_warn_secs: u64) {
+    let run_timeout = Duration::from_secs(run_timeout_secs);
+    let heartbeat_interval_secs = DEFAULT_HEARTBEAT_INTERVAL_SECS;
+    let lease_timeout = Duration::from_secs(heartbeat_interval_secs * 3);
+
+    tracing::info!("Run-queue worker started, polling every {poll_interval}s");
+
+    loop {
+        match CollectorRun::recover_stale_any(&pool, lease_timeout).await {
+            Ok(stale) if stale > 0 => {
+                tracing::warn!("Recovered {stale} stale 'running' run(s) from the queue");
+            }
+            Ok(_) => {}
+            Err(e) => tracing::error!("Run-queue worker failed to recover stale runs: {e}"),
+        }
+
+        match CollectorRun::reap_timed_out(&pool, run_timeout, lease_timeout).await {
+            Ok(timed_out) if timed_out > 0 => {
+                tracing::warn!(
+                    "Reaped {timed_out} queued run(s) stuck in 'running' past {run_timeout_secs}s"
+                );
+            }
+            Ok(_) => {}
+            Err(e) => tracing::error!("Run-queue worker failed to reap timed-out runs: {e}"),
+        }
+
+        match CollectorRun::claim_next_any(&pool).await {
+            Ok(Some(run)) => match get_collector(&run.collector_name) {
+                Some(collector_impl) => {
+                    tracing::info!("Claimed queued run {} for '{}'", run.id, run.collector_name);
+                    let started = Instant::now();
+                    process_run(&pool, &*collector_impl, &run, heartbeat_interval_secs).await;
+                    let elapsed = started.elapsed();
+                    if elapsed > Duration::from_secs(slow_run_warn_secs) {
+                        tracing::warn!(
+                            "Run {} for '{}' took {elapsed:?}, exceeding the {slow_run_warn_secs}s slow-run threshold",
+                            run.id,
+                            run.collector_name
+                        );
+                    }
+                }
+                None => {
+                    let msg = format!(
+                        "No JobCollector implementation registered for '{}'",
+                        run.collector_name
+                    );
+                    tracing::error!("{msg}");
+                    let _ = CollectorRun::mark_failed_permanently(&pool, run.id, &msg).await;
+                }
+            },
+            Ok(None) => {}
+            Err(e) => tracing::error!("Run-queue worker failed to claim a pending run: {e}"),
+        }
+
+        tokio::time::sleep(Duration::from_secs(poll_interval)).await;
+    }
+}
+
+/// Shared heartbeat cadence for every producer of runs under a given
+/// collector_name - CLI workers, the scheduler, and company-scoped ATS
+/// collects alike. `recover_stale` reclaims by collector_name without
+/// knowing which process is heartbeating, so a per-worker cadence (e.g.
+/// derived from one worker's own `--poll-interval`) could give it a lease
+/// shorter than another producer's real heartbeat interval and cause it to
+/// reclaim a healthy run out from under it.
+pub(crate) const DEFAULT_HEARTBEAT_INTERVAL_SECS: u64 = 15;
+
+pub(crate) async fn process_run(
+    pool: &PgPool,
+    collector: &dyn super::JobCollector,
+    run: &CollectorRun,
+    heartbeat_interval_secs: u64,
+) {
     let config = match Collector::get_by_name(pool, &run.collector_name).await {
         Ok(c) => c.config,
         Err(e) => {
             let msg = format!("Failed to load collector config: {e}");
             tracing::error!("{msg}");
-            let _ = CollectorRun::mark_failed(pool, run.id, &msg).await;
+            let exhausted = CollectorRun::mark_failed(pool, run.id, &msg)
+                .await
+                .unwrap_or(true);
+            let _ = Collector::mark_failed(pool, &run.collector_name, &msg, exhausted).await;
             return;
         }
     };
 
-    match collector.collect(&config).await {
+    let _ = Collector::mark_running(pool, &run.collector_name).await;
+
+    // Keep the run's heartbeat fresh while collect() is in flight so
+    // recover_stale doesn't reclaim it out from under us.
+    let heartbeat_pool = pool.clone();
+    let run_id = run.id;
+    let heartbeat_task = tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(Duration::from_secs(heartbeat_interval_secs)).await;
+            let _ = CollectorRun::heartbeat(&heartbeat_pool, run_id).await;
+        }
+    });
+
+    let result = collector
+        .collect(&config)
+        .with_poll_timer("collector.collect")
+        .with_stage_timer(
+            format!("collector.collect[{}, run {}]", run.collector_name, run.id),
+            SLOW_COLLECT_THRESHOLD,
+        )
+        .await;
+    heartbeat_task.abort();
+
+    let notifiers = notifiers_from_config(&config);
+
+    match result {
         Ok(jobs) => {
-            let (found, new, updated) = upsert_jobs(pool, jobs).await;
+            // Only a collector whose whole source belongs to it is safe to
+            // reconcile at DelistScope::Source - a shared ATS platform
+            // (greenhouse, lever, ashby) driven through this durable path
+            // would otherwise expire every other company's open postings
+            // on that platform. collect_company is the only place that
+            // should ever use DelistScope::Company, and only process_run's
+            // globally-scoped collectors should reach DelistScope::Source.
+            let delist_scope = match crate::collectors::scope_of(&run.collector_name) {
+                Some(crate::collectors::CollectorScope::Global) => Some(DelistScope::Source),
+                Some(crate::collectors::CollectorScope::PerCompany) | None => {
+                    tracing::error!(
+                        "Collector '{}' has no CollectorScope::Global registration; refusing to reconcile delisted jobs at source scope for run {} (drive per-company ATS platforms via collect_company instead)",
+                        run.collector_name, run.id
+                    );
+                    None
+                }
+            };
+            let outcome = upsert_jobs(pool, &run.collector_name, run.id, jobs, delist_scope).await;
+            let UpsertOutcome {
+                found,
+                new,
+                updated,
+                delisted,
+                new_jobs,
+            } = outcome;
             tracing::info!(
-                "Run {} completed: {found} found, {new} new, {updated} updated",
+                "Run {} completed: {found} found, {new} new, {updated} updated, {delisted} delisted",
                 run.id
             );
-            let _ = CollectorRun::mark_succeeded(pool, run.id, found, new, updated).await;
-            let _ = Collector::record_run(pool, &run.collector_name, None).await;
+            let _ = CollectorRun::mark_succeeded(pool, run.id, found, new, updated, delisted).await;
+            let _ = Collector::mark_idle(pool, &run.collector_name).await;
+
+            let description =
+                format!("{found} found, {new} new, {updated} updated, {delisted} delisted");
+            let event = Event::create(
+                pool,
+                CreateEvent {
+                    application_id: None,
+                    job_id: None,
+                    event_type: event_type::COLLECTOR_RUN_COMPLETED.to_string(),
+                    description: Some(description),
+                    occurred_at: None,
+                },
+            )
+            .await;
+            if let Err(e) = event {
+                tracing::warn!("Failed to record run-completed event for run {}: {e}", run.id);
+            }
+
+            notify_all(
+                &notifiers,
+                &NotifyEvent::RunCompleted {
+                    collector: run.collector_name.clone(),
+                    run_id: run.id,
+                    found,
+                    new,
+                    updated,
+                },
+            )
+            .await;
+
+            if !new_jobs.is_empty() {
+                notify_all(
+                    &notifiers,
+                    &NotifyEvent::NewJobs {
+                        collector: run.collector_name.clone(),
+                        run_id: run.id,
+                        jobs: new_jobs,
+                    },
+                )
+                .await;
+            }
         }
         Err(e) => {
             let error = e.to_string();
             tracing::error!("Run {} failed: {error}", run.id);
-            let _ = CollectorRun::mark_failed(pool, run.id, &error).await;
-            let _ = Collector::record_run(pool, &run.collector_name, Some(&error)).await;
+            let exhausted = if matches!(e, AppError::PermanentCollectorError(_)) {
+                let _ = CollectorRun::mark_failed_permanently(pool, run.id, &error).await;
+                true
+            } else {
+                CollectorRun::mark_failed(pool, run.id, &error)
+                    .await
+                    .unwrap_or(true)
+            };
+            let _ = Collector::mark_failed(pool, &run.collector_name, &error, exhausted).await;
         }
     }
 }
 
-async fn upsert_jobs(pool: &PgPool, jobs: Vec<CollectedJob>) -> (i32, i32, i32) {
+/// Tallies from a single `upsert_jobs` pass, plus the jobs that were newly
+/// inserted (rather than updated) so the caller can notify on them without
+/// re-querying.
+struct UpsertOutcome {
+    found: i32,
+    new: i32,
+    updated: i32,
+    delisted: i32,
+    new_jobs: Vec<Job>,
+}
+
+async fn upsert_jobs(
+    pool: &PgPool,
+    collector_name: &str,
+    run_id: i32,
+    jobs: Vec<CollectedJob>,
+    delist_scope: Option<DelistScope>,
+) -> UpsertOutcome {
     let found = jobs.len() as i32;
     let mut new = 0;
     let mut updated = 0;
+    let mut new_jobs = Vec::new();
+    let mut seen_by_source: HashMap<String, Vec<String>> = HashMap::new();
 
     for collected in jobs {
-        let company = match Company::find_or_create(pool, &collected.company_name).await {
+        seen_by_source
+            .entry(collected.source.clone())
+            .or_default()
+            .push(collected.source_id.clone());
+
+        let company = match Company::find_or_create(pool, &collected.company_name)
+            .with_poll_timer("Company::find_or_create")
+            .with_stage_timer(
+                format!("Company::find_or_create[{collector_name}, run {run_id}]"),
+                SLOW_UPSERT_THRESHOLD,
+            )
+            .await
+        {
             Ok(c) => c,
             Err(e) => {
                 tracing::warn!(
@@ -116,12 +378,45 @@ async fn upsert_jobs(pool: &PgPool, jobs: Vec<CollectedJob>) -> (i32, i32, i32)
             raw_data: collected.raw_data,
         };
 
-        match Job::upsert(pool, input).await {
-            Ok((_job, was_inserted)) => {
-                if was_inserted {
+        let upsert_result = Job::upsert(pool, input)
+            .with_poll_timer("Job::upsert")
+            .with_stage_timer(
+                format!("Job::upsert[{collector_name}, run {run_id}]"),
+                SLOW_UPSERT_THRESHOLD,
+            )
+            .await;
+        match upsert_result {
+            Ok(outcome) => {
+                let event = if outcome.was_inserted {
                     new += 1;
+                    new_jobs.push(outcome.job.clone());
+                    Some((event_type::JOB_DISCOVERED, None))
                 } else {
                     updated += 1;
+                    outcome
+                        .change_summary
+                        .clone()
+                        .map(|summary| (event_type::JOB_UPDATED, Some(summary)))
+                };
+
+                if let Some((event_type, description)) = event {
+                    let result = Event::create(
+                        pool,
+                        CreateEvent {
+                            application_id: None,
+                            job_id: Some(outcome.job.id),
+                            event_type: event_type.to_string(),
+                            description,
+                            occurred_at: None,
+                        },
+                    )
+                    .await;
+                    if let Err(e) = result {
+                        tracing::warn!(
+                            "Failed to record {event_type} event for job {}: {e}",
+                            outcome.job.id
+                        );
+                    }
                 }
             }
             Err(e) => {
@@ -130,5 +425,133 @@ async fn upsert_jobs(pool: &PgPool, jobs: Vec<CollectedJob>) -> (i32, i32, i32)
         }
     }
 
-    (found, new, updated)
+    // Skip reconciliation entirely when nothing was returned: an empty
+    // result is as likely an upstream hiccup as a genuinely empty board, and
+    // treating it as authoritative would delist every open job for the
+    // source.
+    let mut delisted = 0;
+    if let (true, Some(delist_scope)) = (found > 0, delist_scope) {
+        for (source, seen_source_ids) in &seen_by_source {
+            match Job::mark_delisted_not_in(pool, source, delist_scope, seen_source_ids).await {
+                Ok(count) => delisted += count as i32,
+                Err(e) => {
+                    tracing::warn!("Failed to reconcile delisted jobs for '{source}': {e}");
+                }
+            }
+        }
+    }
+
+    UpsertOutcome {
+        found,
+        new,
+        updated,
+        delisted,
+        new_jobs,
+    }
+}
+
+/// Result of an on-demand, company-scoped ATS collect, returned to the HTTP
+/// handler that triggered it. Mirrors the counts `CollectorRun` tracks, but
+/// this path isn't durable - it doesn't go through the pending/running
+/// queue scheduled and manual collector runs use.
+pub struct CompanyCollectResult {
+    pub run: CollectorRun,
+    pub found: i32,
+    pub new: i32,
+    pub updated: i32,
+    pub delisted: i32,
+}
+
+/// Scrape a single company's public job board via its `ats_platform`
+/// collector and `careers_url`, feeding results through the same
+/// `upsert_jobs` path the durable collector runs use. Unlike `process_run`,
+/// there's no `collectors` table row to read config from here - an ATS
+/// platform has a `JobCollector` in the registry but no `collectors` row,
+/// just a company attribute - so the config is built inline from the company, and the `CollectorRun`
+/// this records is purely an audit trail rather than something that drives
+/// retry or scheduling.
+pub async fn collect_company(
+    pool: &PgPool,
+    company: &Company,
+) -> Result<CompanyCollectResult, AppError> {
+    let platform = company.ats_platform.as_deref().ok_or_else(|| {
+        AppError::BadRequest(format!("Company {} has no ats_platform set", company.id))
+    })?;
+    let careers_url = company.careers_url.as_deref().ok_or_else(|| {
+        AppError::BadRequest(format!("Company {} has no careers_url set", company.id))
+    })?;
+    let collector = get_collector(platform).ok_or_else(|| {
+        AppError::BadRequest(format!(
+            "No collector registered for ATS platform '{platform}'"
+        ))
+    })?;
+
+    let collector_name = format!("company:{}:{platform}", company.id);
+    let run = CollectorRun::enqueue(pool, &collector_name, RunKind::Manual).await?;
+    let run = CollectorRun::claim(pool, run.id).await?;
+
+    let config = serde_json::json!({
+        "careers_url": careers_url,
+        "company_name": company.name,
+    });
+
+    // Heartbeat on the shared cadence while collect() is in flight, same as
+    // process_run, so reap_timed_out doesn't treat a live collect as wedged.
+    let heartbeat_pool = pool.clone();
+    let run_id = run.id;
+    let heartbeat_task = tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(Duration::from_secs(DEFAULT_HEARTBEAT_INTERVAL_SECS)).await;
+            let _ = CollectorRun::heartbeat(&heartbeat_pool, run_id).await;
+        }
+    });
+
+    let result = collector
+        .collect(&config)
+        .with_poll_timer("ats_collector.collect")
+        .with_stage_timer(
+            format!("ats_collector.collect[{collector_name}, run {}]", run.id),
+            SLOW_COLLECT_THRESHOLD,
+        )
+        .await;
+    heartbeat_task.abort();
+
+    match result {
+        Ok(jobs) => {
+            let outcome = upsert_jobs(
+                pool,
+                &collector_name,
+                run.id,
+                jobs,
+                Some(DelistScope::Company(company.id)),
+            )
+            .await;
+            CollectorRun::mark_succeeded(
+                pool,
+                run.id,
+                outcome.found,
+                outcome.new,
+                outcome.updated,
+                outcome.delisted,
+            )
+            .await?;
+            Ok(CompanyCollectResult {
+                run,
+                found: outcome.found,
+                new: outcome.new,
+                updated: outcome.updated,
+                delisted: outcome.delisted,
+            })
+        }
+        Err(e) => {
+            // No worker ever claims a `company:{id}:{platform}` run, so
+            // even a transient failure must terminate it here rather than
+            // go through `mark_failed`'s retry/backoff reschedule - that
+            // would leave the audit row stuck `pending` forever with
+            // nothing to ever claim and finish it.
+            let error = e.to_string();
+            let _ = CollectorRun::mark_failed_permanently(pool, run.id, &error).await;
+            Err(e)
+        }
+    }
 }
@@ -11,6 +11,11 @@ pub struct Config {
     #[arg(long, env = "RUN_MIGRATIONS", default_value = "true")]
     pub run_migrations: bool,
 
+    /// Disable per-statement SQL logging (the job/application/event handlers
+    /// issue a lot of `SELECT *` queries that otherwise flood logs at debug level)
+    #[arg(long, env = "DISABLE_SQL_LOGGING", default_value = "false")]
+    pub disable_sql_logging: bool,
+
     #[command(subcommand)]
     pub command: Option<Command>,
 }
@@ -22,6 +27,33 @@ pub enum Command {
         /// Listen address
         #[arg(long, env = "LISTEN_ADDR", default_value = "0.0.0.0:8080")]
         listen_addr: String,
+
+        /// Disable the background collector scheduler, leaving jobs to
+        /// enter only via the manual ingest endpoint
+        #[arg(long, env = "DISABLE_SCHEDULER", default_value = "false")]
+        disable_scheduler: bool,
+
+        /// How often the scheduler checks for due collectors, in seconds
+        #[arg(long, env = "SCHEDULER_POLL_INTERVAL", default_value = "30")]
+        scheduler_poll_interval: u64,
+
+        /// How often the in-process run-queue worker polls for pending
+        /// collector runs (manual/API-triggered or retried runs). Spawned
+        /// unconditionally, independent of `disable_scheduler`, so
+        /// `POST /collectors/{name}/run` and retries work even with
+        /// scheduled collection off.
+        #[arg(long, env = "RUN_QUEUE_POLL_INTERVAL", default_value = "5")]
+        run_queue_poll_interval: u64,
+
+        /// How long a queued run may sit in "running" before it's
+        /// considered timed out and reaped, in seconds
+        #[arg(long, env = "RUN_TIMEOUT_SECS", default_value = "600")]
+        run_timeout_secs: u64,
+
+        /// Warn if a single claim-and-execute cycle on the run-queue worker
+        /// takes longer than this many seconds
+        #[arg(long, env = "SLOW_RUN_WARN_SECS", default_value = "120")]
+        slow_run_warn_secs: u64,
     },
     /// Run a job collector worker loop
     Collect {
@@ -32,6 +64,18 @@ pub enum Command {
         /// Poll interval in seconds
         #[arg(long, env = "POLL_INTERVAL", default_value = "10")]
         poll_interval: u64,
+
+        /// How long a run may sit in "running" before it's considered timed
+        /// out and reaped, in seconds
+        #[arg(long, env = "RUN_TIMEOUT_SECS", default_value = "600")]
+        run_timeout_secs: u64,
+
+        /// Warn if a single claim-and-execute cycle takes longer than this
+        /// many seconds. Defaults to 20x `poll_interval` (but at least 30s)
+        /// when unset, since a
+        /// slower collector naturally needs a more relaxed threshold.
+        #[arg(long, env = "SLOW_RUN_WARN_SECS")]
+        slow_run_warn_secs: Option<u64>,
     },
 }
 
@@ -41,6 +85,25 @@ impl Config {
         self.command.clone().unwrap_or(Command::Serve {
             listen_addr: std::env::var("LISTEN_ADDR")
                 .unwrap_or_else(|_| "0.0.0.0:8080".to_string()),
+            disable_scheduler: std::env::var("DISABLE_SCHEDULER")
+                .map(|v| v == "true")
+                .unwrap_or(false),
+            scheduler_poll_interval: std::env::var("SCHEDULER_POLL_INTERVAL")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(30),
+            run_queue_poll_interval: std::env::var("RUN_QUEUE_POLL_INTERVAL")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(5),
+            run_timeout_secs: std::env::var("RUN_TIMEOUT_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(600),
+            slow_run_warn_secs: std::env::var("SLOW_RUN_WARN_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(120),
         })
     }
 }
@@ -0,0 +1,57 @@
+use sqlx::PgPool;
+use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
+use std::str::FromStr;
+
+/// How to obtain the pool the rest of the app runs against. Splitting this
+/// out of `main` gives operators one place to tune pool size, timeouts, and
+/// log verbosity, and lets tests inject an already-open pool.
+pub enum ConnectionOptions {
+    /// Open a fresh pool against `url`.
+    Fresh {
+        url: String,
+        pool_options: PgPoolOptions,
+        /// When set, disables sqlx's per-statement `DEBUG` logging. The
+        /// job/application/event handlers each issue a `SELECT *` per
+        /// request, which otherwise floods logs at debug level.
+        disable_logging: bool,
+    },
+    /// Reuse a pool a caller (typically a test) already built.
+    Existing(PgPool),
+}
+
+impl ConnectionOptions {
+    pub async fn connect(self) -> anyhow::Result<PgPool> {
+        match self {
+            ConnectionOptions::Existing(pool) => Ok(pool),
+            ConnectionOptions::Fresh {
+                url,
+                pool_options,
+                disable_logging,
+            } => {
+                let mut connect_options = PgConnectOptions::from_str(&url)?;
+                if disable_logging {
+                    connect_options = connect_options.disable_statement_logging();
+                }
+                let pool = pool_options.connect_with(connect_options).await?;
+                Ok(pool)
+            }
+        }
+    }
+}
+
+/// Build the pool the app uses at startup from a database URL.
+pub async fn create_pool(url: &str, disable_logging: bool) -> anyhow::Result<PgPool> {
+    ConnectionOptions::Fresh {
+        url: url.to_string(),
+        pool_options: PgPoolOptions::new(),
+        disable_logging,
+    }
+    .connect()
+    .await
+}
+
+/// Run pending migrations from the `migrations/` directory.
+pub async fn run_migrations(pool: &PgPool) -> anyhow::Result<()> {
+    sqlx::migrate!().run(pool).await?;
+    Ok(())
+}
@@ -17,6 +17,10 @@ impl IntoResponse for HtmlError {
             }
             AppError::NotFound(msg) => (StatusCode::NOT_FOUND, msg.clone()),
             AppError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
+            AppError::InvalidJob(msg) => (StatusCode::UNPROCESSABLE_ENTITY, msg.clone()),
+            AppError::PermanentCollectorError(msg) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, msg.clone())
+            }
             AppError::Unauthorized => (StatusCode::UNAUTHORIZED, "Unauthorized".to_string()),
             AppError::Internal(msg) => {
                 tracing::error!("Internal error: {msg}");
@@ -65,6 +69,16 @@ pub enum AppError {
     #[error("Bad request: {0}")]
     BadRequest(String),
 
+    #[error("Invalid job: {0}")]
+    InvalidJob(String),
+
+    /// A collector failure that retrying won't fix (malformed config, a
+    /// response shape the parser can't handle). Bypasses the collector
+    /// run's retry/backoff path instead of burning attempts on something
+    /// that will fail identically every time.
+    #[error("Permanent collector error: {0}")]
+    PermanentCollectorError(String),
+
     #[error("Unauthorized")]
     Unauthorized,
 
@@ -94,6 +108,10 @@ impl IntoResponse for AppError {
             }
             AppError::NotFound(msg) => (StatusCode::NOT_FOUND, msg.clone()),
             AppError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
+            AppError::InvalidJob(msg) => (StatusCode::UNPROCESSABLE_ENTITY, msg.clone()),
+            AppError::PermanentCollectorError(msg) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, msg.clone())
+            }
             AppError::Unauthorized => (StatusCode::UNAUTHORIZED, "Unauthorized".to_string()),
             AppError::Internal(msg) => {
                 tracing::error!("Internal error: {msg}");
@@ -4,7 +4,10 @@ mod config;
 mod db;
 mod error;
 mod models;
+mod notifier;
+mod poll_timer;
 mod routes;
+mod scheduler;
 
 use axum::Router;
 use axum::http::StatusCode;
@@ -16,7 +19,7 @@ use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
 use tracing_subscriber::EnvFilter;
 
-use crate::config::Config;
+use crate::config::{Command, Config};
 
 async fn healthz() -> impl IntoResponse {
     (StatusCode::OK, "ok")
@@ -42,7 +45,7 @@ async fn main() -> anyhow::Result<()> {
     let config = Config::parse();
 
     tracing::info!("Connecting to database...");
-    let pool = db::create_pool(&config.database_url).await?;
+    let pool = db::create_pool(&config.database_url, config.disable_sql_logging).await?;
 
     if config.run_migrations {
         tracing::info!("Running database migrations...");
@@ -50,18 +53,67 @@ async fn main() -> anyhow::Result<()> {
         tracing::info!("Migrations complete");
     }
 
-    let readyz_pool = pool.clone();
-    let app = Router::new()
-        .route("/healthz", get(healthz))
-        .route("/readyz", get(move || readyz(readyz_pool.clone())))
-        .merge(routes::ui::router(pool.clone()))
-        .merge(routes::api::router(pool))
-        .layer(TraceLayer::new_for_http())
-        .layer(CorsLayer::permissive());
+    match config.resolved_command() {
+        Command::Serve {
+            listen_addr,
+            disable_scheduler,
+            scheduler_poll_interval,
+            run_queue_poll_interval,
+            run_timeout_secs,
+            slow_run_warn_secs,
+        } => {
+            if !disable_scheduler {
+                let scheduler_pool = pool.clone();
+                tokio::spawn(async move {
+                    scheduler::run(scheduler_pool, scheduler_poll_interval).await;
+                });
+            }
 
-    let listener = tokio::net::TcpListener::bind(&config.listen_addr).await?;
-    tracing::info!("Listening on {}", config.listen_addr);
-    axum::serve(listener, app).await?;
+            // Always spawned, independent of disable_scheduler: manual
+            // (trigger_run) and retried runs are enqueued onto the same
+            // durable queue regardless of whether scheduled collection is
+            // on, and nothing else in serve drains it.
+            let run_queue_pool = pool.clone();
+            tokio::spawn(async move {
+                collectors::runner::run_queue(
+                    run_queue_pool,
+                    run_queue_poll_interval,
+                    run_timeout_secs,
+                    slow_run_warn_secs,
+                )
+                .await;
+            });
+
+            let readyz_pool = pool.clone();
+            let app = Router::new()
+                .route("/healthz", get(healthz))
+                .route("/readyz", get(move || readyz(readyz_pool.clone())))
+                .merge(routes::ui::router(pool.clone()))
+                .merge(routes::api::router(pool))
+                .layer(TraceLayer::new_for_http())
+                .layer(CorsLayer::permissive());
+
+            let listener = tokio::net::TcpListener::bind(&listen_addr).await?;
+            tracing::info!("Listening on {listen_addr}");
+            axum::serve(listener, app).await?;
+        }
+        Command::Collect {
+            collector,
+            poll_interval,
+            run_timeout_secs,
+            slow_run_warn_secs,
+        } => {
+            let slow_run_warn_secs = slow_run_warn_secs.unwrap_or(poll_interval.saturating_mul(20).max(30));
+            collectors::runner::run(
+                pool,
+                &collector,
+                poll_interval,
+                run_timeout_secs,
+                slow_run_warn_secs,
+            )
+            .await?;
+        }
+    }
 
     Ok(())
 }
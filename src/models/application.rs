@@ -1,14 +1,84 @@
+use std::fmt;
+use std::str::FromStr;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 
 use crate::error::AppError;
 
+/// Mirrors the Postgres `application_status` enum. Keeping this as a real
+/// enum (rather than a `String` column) means an unexpected value is
+/// rejected at the database boundary instead of quietly failing string
+/// comparisons elsewhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "application_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ApplicationStatus {
+    Draft,
+    Applied,
+    Interviewing,
+    Offer,
+    Accepted,
+    Rejected,
+    Withdrawn,
+}
+
+impl ApplicationStatus {
+    pub const ALL: [ApplicationStatus; 7] = [
+        ApplicationStatus::Draft,
+        ApplicationStatus::Applied,
+        ApplicationStatus::Interviewing,
+        ApplicationStatus::Offer,
+        ApplicationStatus::Accepted,
+        ApplicationStatus::Rejected,
+        ApplicationStatus::Withdrawn,
+    ];
+}
+
+impl Default for ApplicationStatus {
+    fn default() -> Self {
+        ApplicationStatus::Draft
+    }
+}
+
+impl fmt::Display for ApplicationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ApplicationStatus::Draft => "draft",
+            ApplicationStatus::Applied => "applied",
+            ApplicationStatus::Interviewing => "interviewing",
+            ApplicationStatus::Offer => "offer",
+            ApplicationStatus::Accepted => "accepted",
+            ApplicationStatus::Rejected => "rejected",
+            ApplicationStatus::Withdrawn => "withdrawn",
+        };
+        f.write_str(s)
+    }
+}
+
+impl FromStr for ApplicationStatus {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "draft" => Ok(ApplicationStatus::Draft),
+            "applied" => Ok(ApplicationStatus::Applied),
+            "interviewing" => Ok(ApplicationStatus::Interviewing),
+            "offer" => Ok(ApplicationStatus::Offer),
+            "accepted" => Ok(ApplicationStatus::Accepted),
+            "rejected" => Ok(ApplicationStatus::Rejected),
+            "withdrawn" => Ok(ApplicationStatus::Withdrawn),
+            other => Err(AppError::BadRequest(format!("Unknown application status: {other}"))),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, sqlx::FromRow)]
 pub struct Application {
     pub id: i32,
     pub job_id: i32,
-    pub status: String,
+    pub status: ApplicationStatus,
     pub cv_variant: Option<String>,
     pub applied_at: Option<DateTime<Utc>>,
     pub response_at: Option<DateTime<Utc>>,
@@ -20,14 +90,14 @@ pub struct Application {
 #[derive(Debug, Deserialize)]
 pub struct CreateApplication {
     pub job_id: i32,
-    pub status: Option<String>,
+    pub status: Option<ApplicationStatus>,
     pub cv_variant: Option<String>,
     pub notes: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct UpdateApplication {
-    pub status: Option<String>,
+    pub status: Option<ApplicationStatus>,
     pub cv_variant: Option<String>,
     pub applied_at: Option<DateTime<Utc>>,
     pub response_at: Option<DateTime<Utc>>,
@@ -36,7 +106,7 @@ pub struct UpdateApplication {
 
 #[derive(Debug, Deserialize)]
 pub struct ApplicationFilters {
-    pub status: Option<String>,
+    pub status: Option<ApplicationStatus>,
 }
 
 impl Application {
@@ -45,7 +115,7 @@ impl Application {
         filters: &ApplicationFilters,
     ) -> Result<Vec<Application>, AppError> {
         let apps = sqlx::query_as::<_, Application>(
-            "SELECT * FROM applications WHERE ($1::text IS NULL OR status = $1) ORDER BY created_at DESC",
+            "SELECT * FROM applications WHERE ($1::application_status IS NULL OR status = $1) ORDER BY created_at DESC",
         )
         .bind(&filters.status)
         .fetch_all(pool)
@@ -65,7 +135,7 @@ impl Application {
         pool: &PgPool,
         input: CreateApplication,
     ) -> Result<Application, AppError> {
-        let status = input.status.unwrap_or_else(|| "draft".to_string());
+        let status = input.status.unwrap_or_default();
         let app = sqlx::query_as::<_, Application>(
             "INSERT INTO applications (job_id, status, cv_variant, notes) VALUES ($1, $2, $3, $4) RETURNING *",
         )
@@ -109,8 +179,8 @@ impl Application {
         Ok(())
     }
 
-    pub async fn count_by_status(pool: &PgPool) -> Result<Vec<(String, i64)>, AppError> {
-        let rows: Vec<(String, i64)> = sqlx::query_as(
+    pub async fn count_by_status(pool: &PgPool) -> Result<Vec<(ApplicationStatus, i64)>, AppError> {
+        let rows: Vec<(ApplicationStatus, i64)> = sqlx::query_as(
             "SELECT status, COUNT(*) FROM applications GROUP BY status ORDER BY COUNT(*) DESC",
         )
         .fetch_all(pool)
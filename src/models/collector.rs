@@ -12,10 +12,27 @@ pub struct Collector {
     pub config: serde_json::Value,
     pub last_run_at: Option<DateTime<Utc>>,
     pub last_error: Option<String>,
+    /// Lifecycle state: "idle", "queued", "running", "failed", or "disabled".
+    /// Unlike `enabled`, this tracks what the collector is doing *right now*.
+    pub state: String,
+    /// How often the scheduler should run this collector, in seconds.
+    pub interval_secs: i32,
+    /// The next time the scheduler should claim this collector.
+    pub next_run_at: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// Collector lifecycle states. Kept as plain strings (matching the rest of
+/// the model layer) rather than a `sqlx::Type` enum for now.
+pub mod state {
+    pub const IDLE: &str = "idle";
+    pub const QUEUED: &str = "queued";
+    pub const RUNNING: &str = "running";
+    pub const FAILED: &str = "failed";
+    pub const DISABLED: &str = "disabled";
+}
+
 #[derive(Debug, Deserialize)]
 pub struct UpdateCollector {
     pub enabled: Option<bool>,
@@ -56,15 +73,99 @@ impl Collector {
         Ok(collector)
     }
 
-    pub async fn record_run(
+    /// Move `idle -> queued`, rejecting if the collector is already
+    /// queued or running so callers don't silently double-trigger it.
+    pub async fn mark_queued(pool: &PgPool, name: &str) -> Result<Collector, AppError> {
+        let existing = Self::get_by_name(pool, name).await?;
+        if existing.state == state::QUEUED || existing.state == state::RUNNING {
+            return Err(AppError::BadRequest(format!(
+                "Collector '{name}' is already {}",
+                existing.state
+            )));
+        }
+        let collector = sqlx::query_as::<_, Collector>(
+            "UPDATE collectors SET state = 'queued', updated_at = NOW() WHERE name = $1 RETURNING *",
+        )
+        .bind(name)
+        .fetch_one(pool)
+        .await?;
+        Ok(collector)
+    }
+
+    /// Move `queued -> running`.
+    pub async fn mark_running(pool: &PgPool, name: &str) -> Result<(), AppError> {
+        sqlx::query("UPDATE collectors SET state = 'running', updated_at = NOW() WHERE name = $1")
+            .bind(name)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Move back to `idle` after a successful run.
+    pub async fn mark_idle(pool: &PgPool, name: &str) -> Result<(), AppError> {
+        sqlx::query(
+            "UPDATE collectors SET state = 'idle', last_run_at = NOW(), last_error = NULL, updated_at = NOW() WHERE name = $1",
+        )
+        .bind(name)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Atomically claim the next collector that's `enabled`, due
+    /// (`next_run_at` has elapsed), and either `idle` or `failed`,
+    /// reschedule it, and move it to `queued` in the same transaction so two
+    /// scheduler instances polling concurrently can't both pick it up.
+    ///
+    /// `failed` is included alongside `idle` so a transient upstream blip
+    /// doesn't permanently stop a scheduled collector: `mark_failed` leaves
+    /// a non-exhausted failure in `state = 'failed'`, and only a
+    /// *successful* run reaches `mark_idle`. `disabled` (retries exhausted) is deliberately excluded
+    /// - that one genuinely needs an operator to re-enable it.
+    pub async fn claim_due(pool: &PgPool) -> Result<Option<Collector>, AppError> {
+        let mut tx = pool.begin().await?;
+
+        let collector = sqlx::query_as::<_, Collector>(
+            "SELECT * FROM collectors
+             WHERE enabled AND state IN ('idle', 'failed') AND next_run_at <= NOW()
+             ORDER BY next_run_at
+             LIMIT 1
+             FOR UPDATE SKIP LOCKED",
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(collector) = collector else {
+            return Ok(None);
+        };
+
+        sqlx::query(
+            "UPDATE collectors
+             SET state = 'queued', next_run_at = NOW() + make_interval(secs => interval_secs), updated_at = NOW()
+             WHERE id = $1",
+        )
+        .bind(collector.id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(Some(collector))
+    }
+
+    /// Move to `failed` (or `disabled` once retries are exhausted) after
+    /// a failed run.
+    pub async fn mark_failed(
         pool: &PgPool,
         name: &str,
-        error: Option<&str>,
+        error: &str,
+        exhausted: bool,
     ) -> Result<(), AppError> {
+        let new_state = if exhausted { state::DISABLED } else { state::FAILED };
         sqlx::query(
-            "UPDATE collectors SET last_run_at = NOW(), last_error = $2, updated_at = NOW() WHERE name = $1",
+            "UPDATE collectors SET state = $2, last_run_at = NOW(), last_error = $3, updated_at = NOW() WHERE name = $1",
         )
         .bind(name)
+        .bind(new_state)
         .bind(error)
         .execute(pool)
         .await?;
@@ -1,22 +1,71 @@
+use std::time::Duration;
+
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 use sqlx::PgPool;
 
 use crate::error::AppError;
 
+/// Mirrors the Postgres `collector_run_status` enum. Keeping this as a real
+/// enum (rather than a `String` column) means an unexpected value is
+/// rejected at the database boundary instead of quietly failing string
+/// comparisons elsewhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
+#[sqlx(type_name = "collector_run_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum CollectorRunStatus {
+    Pending,
+    Running,
+    Succeeded,
+    Failed,
+    TimedOut,
+}
+
+/// Mirrors the Postgres `run_kind` enum, distinguishing how a run was
+/// triggered: `Manual` for an on-demand `POST /collectors/{name}/run` and
+/// for every company-scoped ATS collect (scheduled or on demand), `Api`
+/// for the batch ingest endpoint, `Scheduled` for a scheduler-claimed
+/// collector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
+#[sqlx(type_name = "run_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RunKind {
+    Manual,
+    Api,
+    Scheduled,
+}
+
 #[derive(Debug, Serialize, sqlx::FromRow)]
 pub struct CollectorRun {
     pub id: i32,
     pub collector_name: String,
-    pub status: String,
-    pub run_kind: String,
+    pub status: CollectorRunStatus,
+    pub run_kind: RunKind,
     pub jobs_found: Option<i32>,
     pub jobs_new: Option<i32>,
     pub jobs_updated: Option<i32>,
+    pub jobs_failed: Option<i32>,
+    pub jobs_delisted: Option<i32>,
     pub error: Option<String>,
     pub requested_at: DateTime<Utc>,
     pub started_at: Option<DateTime<Utc>>,
     pub finished_at: Option<DateTime<Utc>>,
+    pub attempts: i32,
+    pub max_attempts: i32,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub heartbeat: Option<DateTime<Utc>>,
+}
+
+/// Base delay used for exponential backoff between retries, in seconds.
+const RETRY_BASE_DELAY_SECS: i64 = 2;
+/// Upper bound on the computed backoff delay, in seconds.
+const RETRY_MAX_DELAY_SECS: i64 = 3600;
+
+fn backoff_delay(attempts: i32) -> chrono::Duration {
+    let secs = RETRY_BASE_DELAY_SECS
+        .saturating_mul(1i64 << attempts.clamp(0, 20))
+        .min(RETRY_MAX_DELAY_SECS);
+    chrono::Duration::seconds(secs)
 }
 
 impl CollectorRun {
@@ -24,7 +73,7 @@ impl CollectorRun {
     pub async fn enqueue(
         pool: &PgPool,
         collector_name: &str,
-        run_kind: &str,
+        run_kind: RunKind,
     ) -> Result<CollectorRun, AppError> {
         let run = sqlx::query_as::<_, CollectorRun>(
             "INSERT INTO collector_runs (collector_name, run_kind) VALUES ($1, $2) RETURNING *",
@@ -44,11 +93,12 @@ impl CollectorRun {
         collector_name: &str,
     ) -> Result<Option<CollectorRun>, AppError> {
         let run = sqlx::query_as::<_, CollectorRun>(
-            "UPDATE collector_runs SET status = 'running', started_at = NOW()
+            "UPDATE collector_runs SET status = 'running', started_at = NOW(), heartbeat = NOW()
              WHERE id = (
                  SELECT id FROM collector_runs
                  WHERE collector_name = $1 AND status = 'pending'
-                 ORDER BY requested_at
+                   AND (next_attempt_at IS NULL OR next_attempt_at <= NOW())
+                 ORDER BY next_attempt_at NULLS FIRST, requested_at
                  LIMIT 1
                  FOR UPDATE SKIP LOCKED
              )
@@ -60,6 +110,92 @@ impl CollectorRun {
         Ok(run)
     }
 
+    /// Atomically claim the next pending run for *any* enabled collector,
+    /// instead of pinning to one `collector_name` like `claim_next` does.
+    /// Backs the `serve` process's run-queue worker, which drains whatever
+    /// `trigger_run` or a retried `mark_failed` enqueued regardless of which
+    /// collector it's for - unlike a `collect --collector` worker, there's
+    /// no single name to filter on.
+    ///
+    /// Joining against `collectors` deliberately excludes
+    /// `company:{id}:{platform}` audit rows, which have no `collectors` row
+    /// and are claimed and driven synchronously by `collect_company` itself.
+    pub async fn claim_next_any(pool: &PgPool) -> Result<Option<CollectorRun>, AppError> {
+        let run = sqlx::query_as::<_, CollectorRun>(
+            "UPDATE collector_runs SET status = 'running', started_at = NOW(), heartbeat = NOW()
+             WHERE id = (
+                 SELECT cr.id FROM collector_runs cr
+                 JOIN collectors c ON c.name = cr.collector_name
+                 WHERE cr.status = 'pending' AND c.enabled
+                   AND (cr.next_attempt_at IS NULL OR cr.next_attempt_at <= NOW())
+                 ORDER BY cr.next_attempt_at NULLS FIRST, cr.requested_at
+                 LIMIT 1
+                 FOR UPDATE SKIP LOCKED
+             )
+             RETURNING *",
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(run)
+    }
+
+    /// Atomically claim a specific run by id, for callers (like the ingest
+    /// API) that already know the run they created rather than picking
+    /// whatever is next in the queue. An empty result means it was already
+    /// claimed by someone else, which we treat as a conflict rather than
+    /// silently re-running it.
+    pub async fn claim(pool: &PgPool, id: i32) -> Result<CollectorRun, AppError> {
+        sqlx::query_as::<_, CollectorRun>(
+            "UPDATE collector_runs SET status = 'running', started_at = NOW(), heartbeat = NOW()
+             WHERE id = $1 AND status = 'pending'
+             RETURNING *",
+        )
+        .bind(id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("Run {id} was already claimed")))
+    }
+
+    /// Transition runs stuck in "running" for longer than `timeout` to the
+    /// terminal `timed_out` state, distinct from the heartbeat-based
+    /// `recover_stale` recovery (which assumes a crashed worker and retries).
+    /// A `timed_out` run genuinely ran too long and needs operator attention.
+    ///
+    /// Also requires the heartbeat to be stale by `heartbeat_timeout` (pass
+    /// the same lease timeout `recover_stale` uses): a collect that's run
+    /// long but is still being actively heartbeated is healthy, not wedged,
+    /// and reaping it out from under a live worker would let that worker's
+    /// eventual `mark_succeeded`/`mark_failed` silently resurrect a
+    /// `timed_out` run.
+    pub async fn reap_timed_out(
+        pool: &PgPool,
+        timeout: Duration,
+        heartbeat_timeout: Duration,
+    ) -> Result<u64, AppError> {
+        let timeout_secs = timeout.as_secs() as f64;
+        let heartbeat_timeout_secs = heartbeat_timeout.as_secs() as f64;
+        let result = sqlx::query(
+            "UPDATE collector_runs SET status = 'timed_out', finished_at = NOW()
+             WHERE status = 'running' AND started_at < NOW() - make_interval(secs => $1)
+               AND (heartbeat IS NULL OR heartbeat < NOW() - make_interval(secs => $2))",
+        )
+        .bind(timeout_secs)
+        .bind(heartbeat_timeout_secs)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Update the heartbeat of a run that is actively being worked on, so
+    /// `recover_stale` doesn't mistake it for a crashed worker.
+    pub async fn heartbeat(pool: &PgPool, id: i32) -> Result<(), AppError> {
+        sqlx::query("UPDATE collector_runs SET heartbeat = NOW() WHERE id = $1")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
     /// Mark a run as succeeded with job counts.
     pub async fn mark_succeeded(
         pool: &PgPool,
@@ -67,41 +203,180 @@ impl CollectorRun {
         jobs_found: i32,
         jobs_new: i32,
         jobs_updated: i32,
+        jobs_delisted: i32,
+    ) -> Result<(), AppError> {
+        Self::mark_partial(pool, id, jobs_found, jobs_new, jobs_updated, 0, jobs_delisted).await
+    }
+
+    /// Mark a run as succeeded, also recording how many of the `jobs_found`
+    /// items failed individually (a partial-success ingest) rather than
+    /// aborting the whole batch.
+    ///
+    /// Requires the run to still be `running`: a run that `reap_timed_out`
+    /// already flipped to `timed_out` out from under a slow-but-live worker
+    /// must stay `timed_out`, not get silently resurrected to `succeeded`
+    /// once that worker's collect finally returns.
+    pub async fn mark_partial(
+        pool: &PgPool,
+        id: i32,
+        jobs_found: i32,
+        jobs_new: i32,
+        jobs_updated: i32,
+        jobs_failed: i32,
+        jobs_delisted: i32,
     ) -> Result<(), AppError> {
-        sqlx::query(
-            "UPDATE collector_runs SET status = 'succeeded', jobs_found = $2, jobs_new = $3, jobs_updated = $4, finished_at = NOW() WHERE id = $1",
+        let result = sqlx::query(
+            "UPDATE collector_runs SET status = 'succeeded', jobs_found = $2, jobs_new = $3, jobs_updated = $4, jobs_failed = $5, jobs_delisted = $6, finished_at = NOW() WHERE id = $1 AND status = 'running'",
         )
         .bind(id)
         .bind(jobs_found)
         .bind(jobs_new)
         .bind(jobs_updated)
+        .bind(jobs_failed)
+        .bind(jobs_delisted)
         .execute(pool)
         .await?;
+        if result.rows_affected() == 0 {
+            tracing::warn!(
+                "Run {id} finished successfully but was no longer 'running' (likely reaped as timed_out); leaving its terminal status alone"
+            );
+        }
         Ok(())
     }
 
-    /// Reset stale "running" runs to "failed" on worker startup.
-    /// This handles the case where a worker crashed mid-run.
-    pub async fn recover_stale(pool: &PgPool, collector_name: &str) -> Result<u64, AppError> {
+    /// Reclaim runs whose heartbeat has gone stale, returning them to
+    /// "pending" so another worker can retry them. Unlike a blind
+    /// "running -> failed" sweep, this leaves runs that a live peer is
+    /// still actively heartbeating alone. `lease_timeout` should be a
+    /// multiple (e.g. 3x) of the heartbeat interval, so a peer that's merely
+    /// running a little behind schedule isn't mistaken for crashed.
+    pub async fn recover_stale(
+        pool: &PgPool,
+        collector_name: &str,
+        lease_timeout: Duration,
+    ) -> Result<u64, AppError> {
+        let lease_timeout_secs = lease_timeout.as_secs() as f64;
         let result = sqlx::query(
-            "UPDATE collector_runs SET status = 'failed', error = 'Worker crashed (stale recovery)', finished_at = NOW()
-             WHERE collector_name = $1 AND status = 'running'",
+            "UPDATE collector_runs SET status = 'pending', started_at = NULL, heartbeat = NULL
+             WHERE collector_name = $1 AND status = 'running'
+               AND heartbeat < NOW() - make_interval(secs => $2)",
         )
         .bind(collector_name)
+        .bind(lease_timeout_secs)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Same as `recover_stale`, but across every collector with a
+    /// `collectors` row instead of one `collector_name` - pairs with
+    /// `claim_next_any` for the run-queue worker, which isn't pinned to a
+    /// single collector. The join excludes `company:{id}:{platform}` audit
+    /// rows for the same reason `claim_next_any` does.
+    pub async fn recover_stale_any(pool: &PgPool, lease_timeout: Duration) -> Result<u64, AppError> {
+        let lease_timeout_secs = lease_timeout.as_secs() as f64;
+        let result = sqlx::query(
+            "UPDATE collector_runs SET status = 'pending', started_at = NULL, heartbeat = NULL
+             WHERE status = 'running'
+               AND heartbeat < NOW() - make_interval(secs => $1)
+               AND collector_name IN (SELECT name FROM collectors)",
+        )
+        .bind(lease_timeout_secs)
         .execute(pool)
         .await?;
         Ok(result.rows_affected())
     }
 
-    /// Mark a run as failed with an error message.
-    pub async fn mark_failed(pool: &PgPool, id: i32, error: &str) -> Result<(), AppError> {
-        sqlx::query(
-            "UPDATE collector_runs SET status = 'failed', error = $2, finished_at = NOW() WHERE id = $1",
+    /// Mark a run as failed. If retries remain, reschedule it with an
+    /// exponentially-backed-off `next_attempt_at` instead of failing it
+    /// terminally; only once `attempts >= max_attempts` does it stay failed.
+    /// Returns `true` if the failure was terminal (retries exhausted).
+    ///
+    /// The status transition itself requires the run to still be `running`,
+    /// for the same reason `mark_partial` does - don't resurrect a run that
+    /// `reap_timed_out` already flipped to `timed_out`.
+    pub async fn mark_failed(pool: &PgPool, id: i32, error: &str) -> Result<bool, AppError> {
+        let run = sqlx::query_as::<_, CollectorRun>(
+            "UPDATE collector_runs SET attempts = attempts + 1 WHERE id = $1 RETURNING *",
+        )
+        .bind(id)
+        .fetch_one(pool)
+        .await?;
+
+        let exhausted = run.attempts >= run.max_attempts;
+        let result = if !exhausted {
+            let next_attempt_at = Utc::now() + backoff_delay(run.attempts);
+            sqlx::query(
+                "UPDATE collector_runs SET status = 'pending', error = $2, started_at = NULL, finished_at = NULL, heartbeat = NULL, next_attempt_at = $3 WHERE id = $1 AND status = 'running'",
+            )
+            .bind(id)
+            .bind(error)
+            .bind(next_attempt_at)
+            .execute(pool)
+            .await?
+        } else {
+            sqlx::query(
+                "UPDATE collector_runs SET status = 'failed', error = $2, finished_at = NOW() WHERE id = $1 AND status = 'running'",
+            )
+            .bind(id)
+            .bind(error)
+            .execute(pool)
+            .await?
+        };
+        if result.rows_affected() == 0 {
+            tracing::warn!(
+                "Run {id} failed but was no longer 'running' (likely reaped as timed_out); leaving its terminal status alone"
+            );
+        }
+        Ok(exhausted)
+    }
+
+    /// Find a run for `collector_name` that has been `running` (and hasn't
+    /// finished) for longer than `threshold`, if one exists. Used to flag a
+    /// collector as "stalled" in the admin UI well before `reap_timed_out`
+    /// would actually act on it.
+    pub async fn running_longer_than(
+        pool: &PgPool,
+        collector_name: &str,
+        threshold: Duration,
+    ) -> Result<Option<CollectorRun>, AppError> {
+        let threshold_secs = threshold.as_secs() as f64;
+        let run = sqlx::query_as::<_, CollectorRun>(
+            "SELECT * FROM collector_runs
+             WHERE collector_name = $1 AND status = 'running' AND finished_at IS NULL
+               AND started_at < NOW() - make_interval(secs => $2)
+             ORDER BY started_at ASC
+             LIMIT 1",
+        )
+        .bind(collector_name)
+        .bind(threshold_secs)
+        .fetch_optional(pool)
+        .await?;
+        Ok(run)
+    }
+
+    /// Mark a run as terminally failed without consuming a retry attempt,
+    /// for errors that retrying can't fix (e.g. `AppError::PermanentCollectorError`).
+    /// Unlike `mark_failed`, this never reschedules via `next_attempt_at`.
+    ///
+    /// Also requires the run to still be `running`, same as `mark_partial`.
+    pub async fn mark_failed_permanently(
+        pool: &PgPool,
+        id: i32,
+        error: &str,
+    ) -> Result<(), AppError> {
+        let result = sqlx::query(
+            "UPDATE collector_runs SET status = 'failed', error = $2, finished_at = NOW() WHERE id = $1 AND status = 'running'",
         )
         .bind(id)
         .bind(error)
         .execute(pool)
         .await?;
+        if result.rows_affected() == 0 {
+            tracing::warn!(
+                "Run {id} failed but was no longer 'running' (likely reaped as timed_out); leaving its terminal status alone"
+            );
+        }
         Ok(())
     }
 
@@ -12,6 +12,12 @@ pub struct Company {
     pub careers_url: Option<String>,
     pub ats_platform: Option<String>,
     pub notes: Option<String>,
+    /// How often the scheduler should sweep this company's ATS board, in
+    /// seconds. Only consulted when `ats_platform` is set.
+    pub ats_interval_secs: i32,
+    /// The next time the scheduler should claim this company for an ATS
+    /// collect.
+    pub ats_next_run_at: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -65,6 +71,26 @@ impl Company {
         Ok(company)
     }
 
+    /// Resolve a company by name, creating it if it doesn't exist yet.
+    /// Collectors only know a posting's company *name*, not its internal
+    /// id, so this is how the ingest/runner pipeline turns that into a
+    /// `company_id`. Takes a generic executor so callers can run it inside
+    /// a transaction alongside the job upsert it precedes.
+    pub async fn find_or_create<'e, E>(executor: E, name: &str) -> Result<Company, AppError>
+    where
+        E: sqlx::PgExecutor<'e>,
+    {
+        let company = sqlx::query_as::<_, Company>(
+            "INSERT INTO companies (name) VALUES ($1)
+             ON CONFLICT (name) DO UPDATE SET name = EXCLUDED.name
+             RETURNING *",
+        )
+        .bind(name)
+        .fetch_one(executor)
+        .await?;
+        Ok(company)
+    }
+
     pub async fn update(
         pool: &PgPool,
         id: i32,
@@ -84,4 +110,40 @@ impl Company {
         .await?;
         Ok(company)
     }
+
+    /// Atomically claim the next company due for a scheduled ATS collect
+    /// (`ats_platform` and `careers_url` set, `ats_next_run_at` elapsed),
+    /// rescheduling it in the same transaction so two scheduler instances
+    /// polling concurrently can't both pick up the same company - mirrors
+    /// `Collector::claim_due`.
+    pub async fn claim_due_for_ats_collect(pool: &PgPool) -> Result<Option<Company>, AppError> {
+        let mut tx = pool.begin().await?;
+
+        let company = sqlx::query_as::<_, Company>(
+            "SELECT * FROM companies
+             WHERE ats_platform IS NOT NULL AND careers_url IS NOT NULL
+               AND ats_next_run_at <= NOW()
+             ORDER BY ats_next_run_at
+             LIMIT 1
+             FOR UPDATE SKIP LOCKED",
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(company) = company else {
+            return Ok(None);
+        };
+
+        sqlx::query(
+            "UPDATE companies
+             SET ats_next_run_at = NOW() + make_interval(secs => ats_interval_secs), updated_at = NOW()
+             WHERE id = $1",
+        )
+        .bind(company.id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(Some(company))
+    }
 }
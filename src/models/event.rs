@@ -15,6 +15,15 @@ pub struct Event {
     pub created_at: DateTime<Utc>,
 }
 
+/// Event types emitted automatically by the collector/ingest pipeline.
+/// Hand-logged events (via the API or the UI form) aren't restricted to
+/// these, so `event_type` stays a plain `String` rather than an enum.
+pub mod event_type {
+    pub const JOB_DISCOVERED: &str = "job_discovered";
+    pub const JOB_UPDATED: &str = "job_updated";
+    pub const COLLECTOR_RUN_COMPLETED: &str = "collector_run_completed";
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateEvent {
     pub application_id: Option<i32>,
@@ -28,21 +37,39 @@ pub struct CreateEvent {
 pub struct EventFilters {
     pub application_id: Option<i32>,
     pub job_id: Option<i32>,
+    pub event_type: Option<String>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
 }
 
 impl Event {
     pub async fn list(pool: &PgPool, filters: &EventFilters) -> Result<Vec<Event>, AppError> {
         let events = sqlx::query_as::<_, Event>(
-            "SELECT * FROM events WHERE ($1::int4 IS NULL OR application_id = $1) AND ($2::int4 IS NULL OR job_id = $2) ORDER BY occurred_at DESC LIMIT 100",
+            "SELECT * FROM events
+             WHERE ($1::int4 IS NULL OR application_id = $1)
+               AND ($2::int4 IS NULL OR job_id = $2)
+               AND ($3::text IS NULL OR event_type = $3)
+               AND ($4::timestamptz IS NULL OR occurred_at >= $4)
+               AND ($5::timestamptz IS NULL OR occurred_at <= $5)
+             ORDER BY occurred_at DESC LIMIT 100",
         )
         .bind(filters.application_id)
         .bind(filters.job_id)
+        .bind(&filters.event_type)
+        .bind(filters.since)
+        .bind(filters.until)
         .fetch_all(pool)
         .await?;
         Ok(events)
     }
 
-    pub async fn create(pool: &PgPool, input: CreateEvent) -> Result<Event, AppError> {
+    /// Takes a generic executor (rather than just `&PgPool`) so automatic
+    /// event emission from `Job::upsert` callers can write the event in the
+    /// same transaction as the job it describes.
+    pub async fn create<'e, E>(executor: E, input: CreateEvent) -> Result<Event, AppError>
+    where
+        E: sqlx::PgExecutor<'e>,
+    {
         let occurred_at = input.occurred_at.unwrap_or_else(Utc::now);
         let event = sqlx::query_as::<_, Event>(
             "INSERT INTO events (application_id, job_id, event_type, description, occurred_at) VALUES ($1, $2, $3, $4, $5) RETURNING *",
@@ -52,7 +79,7 @@ impl Event {
         .bind(&input.event_type)
         .bind(&input.description)
         .bind(occurred_at)
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await?;
         Ok(event)
     }
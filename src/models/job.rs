@@ -4,7 +4,7 @@ use sqlx::PgPool;
 
 use crate::error::AppError;
 
-#[derive(Debug, Serialize, sqlx::FromRow)]
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
 pub struct Job {
     pub id: i32,
     pub company_id: i32,
@@ -57,6 +57,60 @@ pub struct UpdateJob {
     pub expires_at: Option<DateTime<Utc>>,
 }
 
+/// Row shape for `Job::upsert`'s `RETURNING` clause: the upserted job, plus
+/// whether it was newly inserted, plus the pre-upsert values of the fields
+/// we track for change detection (all `NULL` when it was an insert).
+#[derive(sqlx::FromRow)]
+struct JobUpsertRow {
+    #[sqlx(flatten)]
+    job: Job,
+    was_inserted: bool,
+    old_title: Option<String>,
+    old_url: Option<String>,
+    old_salary_min: Option<i32>,
+    old_salary_max: Option<i32>,
+}
+
+/// Result of `Job::upsert`: the row, whether it was newly inserted, and a
+/// human-readable summary of what changed if it was an update to an
+/// existing posting (used to populate the `job_updated` event).
+pub struct JobUpsertOutcome {
+    pub job: Job,
+    pub was_inserted: bool,
+    pub change_summary: Option<String>,
+}
+
+/// Build a human-readable summary of what changed between the pre-upsert
+/// row captured in `old` and the newly-upserted `row.job`. Only title, url,
+/// and salary are tracked; other field changes (location, description,
+/// raw_data, ...) don't warrant a `job_updated` event on their own.
+fn diff_summary(row: &JobUpsertRow) -> Option<String> {
+    let mut changes = Vec::new();
+
+    if let Some(old_title) = &row.old_title
+        && old_title != &row.job.title
+    {
+        changes.push(format!("title: {old_title:?} -> {:?}", row.job.title));
+    }
+
+    if row.old_url != row.job.url {
+        changes.push(format!("url: {:?} -> {:?}", row.old_url, row.job.url));
+    }
+
+    if row.old_salary_min != row.job.salary_min || row.old_salary_max != row.job.salary_max {
+        changes.push(format!(
+            "salary: {:?}-{:?} -> {:?}-{:?}",
+            row.old_salary_min, row.old_salary_max, row.job.salary_min, row.job.salary_max
+        ));
+    }
+
+    if changes.is_empty() {
+        None
+    } else {
+        Some(changes.join("; "))
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct JobFilters {
     pub source: Option<String>,
@@ -65,6 +119,18 @@ pub struct JobFilters {
     pub per_page: Option<i64>,
 }
 
+/// How widely a `Job::mark_delisted_not_in` call is allowed to reconcile.
+/// Some sources (hiringcafe) are a single collector's own global search, so
+/// every job under that source was covered by one cycle's results. Others
+/// (greenhouse, lever, ashby) are shared ATS platform names spanning many
+/// companies' independent boards, where only one company's slice was just
+/// collected - reconciliation must stay scoped to that company.
+#[derive(Debug, Clone, Copy)]
+pub enum DelistScope {
+    Source,
+    Company(i32),
+}
+
 impl Job {
     pub async fn list(pool: &PgPool, filters: &JobFilters) -> Result<Vec<Job>, AppError> {
         let per_page = filters.per_page.unwrap_or(50).min(100);
@@ -113,6 +179,77 @@ impl Job {
         Ok(job)
     }
 
+    /// Source-keyed upsert: insert a job, or update it in place if a posting
+    /// with the same `(source, source_id)` was already seen (backed by the
+    /// partial unique index `jobs_source_source_id_key`). Returns the row,
+    /// whether it was newly inserted, and a change summary if an existing
+    /// posting's title/url/salary changed, so callers can tally
+    /// new-vs-updated counts for a run and emit `job_discovered`/
+    /// `job_updated` events. Manually-entered jobs without a `source_id`
+    /// always insert, since the backing index is partial on
+    /// `source_id IS NOT NULL`.
+    ///
+    /// The pre-upsert values are captured via a CTE rather than a separate
+    /// SELECT, so this stays a single round trip: a CTE in a data-modifying
+    /// statement sees the table as it was before the statement's own
+    /// effects, so `old` reflects the row prior to this upsert.
+    pub async fn upsert<'e, E>(executor: E, input: CreateJob) -> Result<JobUpsertOutcome, AppError>
+    where
+        E: sqlx::PgExecutor<'e>,
+    {
+        let row = sqlx::query_as::<_, JobUpsertRow>(
+            "WITH old AS (
+                SELECT title, url, salary_min, salary_max FROM jobs WHERE source = $11 AND source_id = $12
+             )
+             INSERT INTO jobs (company_id, title, url, location, remote_type, salary_min, salary_max, salary_currency, description, requirements, source, source_id, expires_at, raw_data)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+             ON CONFLICT (source, source_id) WHERE source_id IS NOT NULL DO UPDATE SET
+                title = EXCLUDED.title,
+                url = EXCLUDED.url,
+                location = EXCLUDED.location,
+                remote_type = EXCLUDED.remote_type,
+                salary_min = EXCLUDED.salary_min,
+                salary_max = EXCLUDED.salary_max,
+                salary_currency = EXCLUDED.salary_currency,
+                description = EXCLUDED.description,
+                raw_data = EXCLUDED.raw_data,
+                updated_at = NOW()
+             RETURNING jobs.*, (xmax = 0) AS was_inserted,
+                (SELECT title FROM old) AS old_title,
+                (SELECT url FROM old) AS old_url,
+                (SELECT salary_min FROM old) AS old_salary_min,
+                (SELECT salary_max FROM old) AS old_salary_max",
+        )
+        .bind(input.company_id)
+        .bind(&input.title)
+        .bind(&input.url)
+        .bind(&input.location)
+        .bind(&input.remote_type)
+        .bind(input.salary_min)
+        .bind(input.salary_max)
+        .bind(&input.salary_currency)
+        .bind(&input.description)
+        .bind(&input.requirements)
+        .bind(&input.source)
+        .bind(&input.source_id)
+        .bind(input.expires_at)
+        .bind(&input.raw_data)
+        .fetch_one(executor)
+        .await?;
+
+        let change_summary = if row.was_inserted {
+            None
+        } else {
+            diff_summary(&row)
+        };
+
+        Ok(JobUpsertOutcome {
+            job: row.job,
+            was_inserted: row.was_inserted,
+            change_summary,
+        })
+    }
+
     pub async fn update(pool: &PgPool, id: i32, input: UpdateJob) -> Result<Job, AppError> {
         let existing = Self::get(pool, id).await?;
         let job = sqlx::query_as::<_, Job>(
@@ -133,6 +270,46 @@ impl Job {
         Ok(job)
     }
 
+    /// Delisting reconciliation: mark jobs for `source` as expired
+    /// (`expires_at = NOW()`) when their `source_id` wasn't among
+    /// `seen_source_ids` this cycle, meaning the posting vanished from the
+    /// upstream board. Only touches rows whose `expires_at` is still unset
+    /// and that have a `source_id` (manually-entered jobs have none and are never
+    /// reconciled). Callers should skip calling this entirely when
+    /// `seen_source_ids` is empty due to an upstream hiccup rather than a
+    /// genuinely empty board, or every open job for the source gets wiped.
+    ///
+    /// `scope` is mandatory rather than an optional company filter: a
+    /// `source` like `"greenhouse"` is shared by every company on that ATS
+    /// platform, so a per-company collect must pass
+    /// `DelistScope::Company(id)` or it will expire every *other* company's
+    /// open postings on the same platform too. Only a collector that owns
+    /// its entire source outright (hiringcafe's global search) should pass
+    /// `DelistScope::Source`.
+    pub async fn mark_delisted_not_in(
+        pool: &PgPool,
+        source: &str,
+        scope: DelistScope,
+        seen_source_ids: &[String],
+    ) -> Result<u64, AppError> {
+        let company_id = match scope {
+            DelistScope::Source => None,
+            DelistScope::Company(id) => Some(id),
+        };
+        let result = sqlx::query(
+            "UPDATE jobs SET expires_at = NOW()
+             WHERE source = $1 AND source_id IS NOT NULL
+               AND source_id <> ALL($2) AND expires_at IS NULL
+               AND ($3::int IS NULL OR company_id = $3)",
+        )
+        .bind(source)
+        .bind(seen_source_ids)
+        .bind(company_id)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
     pub async fn delete(pool: &PgPool, id: i32) -> Result<(), AppError> {
         let result = sqlx::query("DELETE FROM jobs WHERE id = $1")
             .bind(id)
@@ -0,0 +1,68 @@
+pub mod stdout;
+pub mod webhook;
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::error::AppError;
+use crate::models::job::Job;
+
+/// Something worth telling the outside world about, emitted by the
+/// collector runner. Carries only what a backend needs to render a useful
+/// message, not full internal state.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NotifyEvent {
+    RunCompleted {
+        collector: String,
+        run_id: i32,
+        found: i32,
+        new: i32,
+        updated: i32,
+    },
+    NewJobs {
+        collector: String,
+        run_id: i32,
+        jobs: Vec<Job>,
+    },
+}
+
+/// A destination for `NotifyEvent`s. Implementations should treat their own
+/// failures as non-fatal to the caller - a broken webhook shouldn't fail a
+/// collector run, just get logged.
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn notify(&self, event: &NotifyEvent) -> Result<(), AppError>;
+}
+
+/// Build the notifiers configured for a collector, read from its JSONB
+/// config under a `"notify"` object, e.g.
+/// `{"notify": {"webhookUrl": "https://...", "stdout": true}}`. Unset or
+/// malformed entries are silently skipped rather than failing collect().
+pub fn notifiers_from_config(config: &serde_json::Value) -> Vec<Box<dyn Notifier>> {
+    let mut notifiers: Vec<Box<dyn Notifier>> = Vec::new();
+
+    let Some(notify) = config.get("notify") else {
+        return notifiers;
+    };
+
+    if let Some(url) = notify.get("webhookUrl").and_then(|v| v.as_str()) {
+        notifiers.push(Box::new(webhook::WebhookNotifier::new(url.to_string())));
+    }
+
+    if notify.get("stdout").and_then(|v| v.as_bool()).unwrap_or(false) {
+        notifiers.push(Box::new(stdout::StdoutNotifier));
+    }
+
+    notifiers
+}
+
+/// Dispatch `event` to every configured notifier, logging (not propagating)
+/// any failure so a flaky Slack webhook never fails the collector run itself.
+pub async fn notify_all(notifiers: &[Box<dyn Notifier>], event: &NotifyEvent) {
+    for notifier in notifiers {
+        if let Err(e) = notifier.notify(event).await {
+            tracing::warn!("Notifier failed: {e}");
+        }
+    }
+}
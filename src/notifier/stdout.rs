@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use super::{NotifyEvent, Notifier};
+use crate::error::AppError;
+
+/// Logs each event via `tracing` - mainly useful for local development and
+/// for collectors that just want a visible record without standing up a
+/// webhook receiver.
+pub struct StdoutNotifier;
+
+#[async_trait]
+impl Notifier for StdoutNotifier {
+    async fn notify(&self, event: &NotifyEvent) -> Result<(), AppError> {
+        match serde_json::to_string(event) {
+            Ok(json) => tracing::info!("{json}"),
+            Err(e) => tracing::warn!("Failed to serialize notify event: {e}"),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use reqwest::Client;
+
+use super::{NotifyEvent, Notifier};
+use crate::error::AppError;
+
+/// Posts each event as JSON to a configured URL - the generic backend for
+/// wiring into Slack/Discord/custom endpoints via an incoming-webhook URL.
+pub struct WebhookNotifier {
+    url: String,
+    client: Client,
+}
+
+impl WebhookNotifier {
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            client: Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    async fn notify(&self, event: &NotifyEvent) -> Result<(), AppError> {
+        self.client
+            .post(&self.url)
+            .json(event)
+            .send()
+            .await
+            .map_err(|e| AppError::Internal(format!("Webhook notify failed: {e}")))?
+            .error_for_status()
+            .map_err(|e| AppError::Internal(format!("Webhook returned an error status: {e}")))?;
+        Ok(())
+    }
+}
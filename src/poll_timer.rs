@@ -0,0 +1,102 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use pin_project_lite::pin_project;
+
+/// How long a single `poll()` call may take before we consider it a
+/// runtime stall (synchronous work, or an executor-blocking DB call)
+/// worth warning about.
+const SLOW_POLL_THRESHOLD: Duration = Duration::from_millis(10);
+
+pin_project! {
+    /// Wraps a future and measures the wall-clock time spent in each
+    /// individual `poll()`, emitting a `tracing::warn!` when it exceeds
+    /// [`SLOW_POLL_THRESHOLD`]. A slow poll means something blocked the
+    /// async runtime instead of yielding, which stalls every other task
+    /// on the same executor thread.
+    pub struct PollTimer<F> {
+        #[pin]
+        inner: F,
+        name: &'static str,
+    }
+}
+
+impl<F: Future> Future for PollTimer<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let start = Instant::now();
+        let result = this.inner.poll(cx);
+        let elapsed = start.elapsed();
+        if elapsed > SLOW_POLL_THRESHOLD {
+            tracing::warn!(name = *this.name, ?elapsed, "slow poll detected");
+        }
+        result
+    }
+}
+
+/// Extension trait adding `.with_poll_timer(name)` to any future.
+pub trait WithPollTimer: Future + Sized {
+    fn with_poll_timer(self, name: &'static str) -> PollTimer<Self> {
+        PollTimer { inner: self, name }
+    }
+}
+
+impl<F: Future> WithPollTimer for F {}
+
+pin_project! {
+    /// Wraps a future and warns if its *total* wall-clock duration, from
+    /// first poll to completion, exceeds `threshold` - unlike [`PollTimer`],
+    /// which only flags a single blocking `poll()` call. A future that yields
+    /// properly on every `poll()` (e.g. while awaiting a slow HTTP response)
+    /// never trips `PollTimer`, so this is what catches a hung upstream call
+    /// or a slow DB round trip.
+    pub struct StageTimer<F> {
+        #[pin]
+        inner: F,
+        name: String,
+        threshold: Duration,
+        start: Option<Instant>,
+    }
+}
+
+impl<F: Future> Future for StageTimer<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let start = *this.start.get_or_insert_with(Instant::now);
+        match this.inner.poll(cx) {
+            Poll::Ready(output) => {
+                let elapsed = start.elapsed();
+                if elapsed > *this.threshold {
+                    tracing::warn!(
+                        name = this.name.as_str(),
+                        ?elapsed,
+                        threshold = ?this.threshold,
+                        "slow stage detected"
+                    );
+                }
+                Poll::Ready(output)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Extension trait adding `.with_stage_timer(name, threshold)` to any future.
+pub trait WithStageTimer: Future + Sized {
+    fn with_stage_timer(self, name: impl Into<String>, threshold: Duration) -> StageTimer<Self> {
+        StageTimer {
+            inner: self,
+            name: name.into(),
+            threshold,
+            start: None,
+        }
+    }
+}
+
+impl<F: Future> WithStageTimer for F {}
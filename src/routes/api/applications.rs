@@ -6,12 +6,15 @@ use crate::error::AppError;
 use crate::models::application::{
     Application, ApplicationFilters, CreateApplication, UpdateApplication,
 };
+use crate::poll_timer::WithPollTimer;
 
 pub async fn list(
     State(pool): State<PgPool>,
     Query(filters): Query<ApplicationFilters>,
 ) -> Result<Json<Vec<Application>>, AppError> {
-    let apps = Application::list(&pool, &filters).await?;
+    let apps = Application::list(&pool, &filters)
+        .with_poll_timer("Application::list")
+        .await?;
     Ok(Json(apps))
 }
 
@@ -19,7 +22,9 @@ pub async fn get(
     State(pool): State<PgPool>,
     Path(id): Path<i32>,
 ) -> Result<Json<Application>, AppError> {
-    let app = Application::get(&pool, id).await?;
+    let app = Application::get(&pool, id)
+        .with_poll_timer("Application::get")
+        .await?;
     Ok(Json(app))
 }
 
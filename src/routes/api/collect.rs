@@ -4,8 +4,9 @@ use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 
 use crate::error::AppError;
-use crate::models::collector_run::CollectorRun;
+use crate::models::collector_run::{CollectorRun, RunKind};
 use crate::models::company::Company;
+use crate::models::event::{CreateEvent, Event, event_type};
 use crate::models::job::{CreateJob, Job};
 
 #[derive(Debug, Deserialize)]
@@ -30,12 +31,21 @@ pub struct IngestJob {
     pub raw_data: Option<serde_json::Value>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct IngestError {
+    pub source_id: String,
+    pub source: String,
+    pub message: String,
+}
+
 #[derive(Debug, Serialize)]
 pub struct IngestResponse {
     pub run_id: i32,
     pub found: i32,
     pub new: i32,
     pub updated: i32,
+    pub failed: i32,
+    pub errors: Vec<IngestError>,
 }
 
 /// POST /api/v1/collect/ingest
@@ -43,6 +53,10 @@ pub struct IngestResponse {
 /// Batch ingest jobs from an external collector. Resolves company names
 /// to IDs internally and upserts each job. Creates a collector_run record
 /// for audit trail.
+///
+/// Each job is resolved and upserted in its own transaction, so one bad
+/// posting (an unresolvable company, a malformed field) only loses that
+/// item instead of aborting the whole batch.
 pub async fn ingest(
     State(pool): State<PgPool>,
     Json(input): Json<IngestRequest>,
@@ -51,57 +65,98 @@ pub async fn ingest(
         return Err(AppError::BadRequest("No jobs provided".to_string()));
     }
 
-    // Create a collector run record for this ingest
-    let run = CollectorRun::enqueue(&pool, &input.collector_name, "api").await?;
-
-    // Claim it immediately (transition pending -> running)
-    sqlx::query("UPDATE collector_runs SET status = 'running', started_at = NOW() WHERE id = $1")
-        .bind(run.id)
-        .execute(&pool)
-        .await?;
-
-    let mut found = 0i32;
-    let mut new = 0i32;
-    let mut updated = 0i32;
+    // Create a collector run record for this ingest, then atomically claim
+    // it (pending -> running) the same way the worker loop does, instead of
+    // an inline SQL transition with no failure path.
+    let run = CollectorRun::enqueue(&pool, &input.collector_name, RunKind::Api).await?;
+    let run = CollectorRun::claim(&pool, run.id).await?;
 
-    for ingest_job in &input.jobs {
-        found += 1;
-
-        // Resolve company name to ID (creates if needed)
-        let company = Company::find_or_create(&pool, &ingest_job.company_name).await?;
-
-        let create_job = CreateJob {
-            company_id: company.id,
-            title: ingest_job.title.clone(),
-            url: ingest_job.url.clone(),
-            location: ingest_job.location.clone(),
-            remote_type: ingest_job.remote_type.clone(),
-            salary_min: ingest_job.salary_min,
-            salary_max: ingest_job.salary_max,
-            salary_currency: ingest_job.salary_currency.clone(),
-            description: ingest_job.description.clone(),
-            requirements: None,
-            source: ingest_job.source.clone(),
-            source_id: Some(ingest_job.source_id.clone()),
-            expires_at: None,
-            raw_data: ingest_job.raw_data.clone(),
-        };
-
-        let (_job, was_inserted) = Job::upsert(&pool, create_job).await?;
-        if was_inserted {
-            new += 1;
-        } else {
-            updated += 1;
-        }
-    }
+    let (new, updated, errors) = ingest_jobs(&pool, &input.jobs).await;
+    let found = input.jobs.len() as i32;
+    let failed = errors.len() as i32;
 
-    // Mark run as succeeded with counts
-    CollectorRun::mark_succeeded(&pool, run.id, found, new, updated).await?;
+    CollectorRun::mark_partial(&pool, run.id, found, new, updated, failed, 0).await?;
 
     Ok(Json(IngestResponse {
         run_id: run.id,
         found,
         new,
         updated,
+        failed,
+        errors,
     }))
 }
+
+async fn ingest_jobs(pool: &PgPool, jobs: &[IngestJob]) -> (i32, i32, Vec<IngestError>) {
+    let mut new = 0i32;
+    let mut updated = 0i32;
+    let mut errors = Vec::new();
+
+    for ingest_job in jobs {
+        match ingest_one(pool, ingest_job).await {
+            Ok(true) => new += 1,
+            Ok(false) => updated += 1,
+            Err(e) => errors.push(IngestError {
+                source_id: ingest_job.source_id.clone(),
+                source: ingest_job.source.clone(),
+                message: e.to_string(),
+            }),
+        }
+    }
+
+    (new, updated, errors)
+}
+
+/// Resolve the company and upsert a single job inside its own transaction,
+/// so a failure here rolls back only this item. Returns whether the job
+/// was newly inserted.
+async fn ingest_one(pool: &PgPool, ingest_job: &IngestJob) -> Result<bool, AppError> {
+    let mut tx = pool.begin().await?;
+
+    let company = Company::find_or_create(&mut *tx, &ingest_job.company_name).await?;
+
+    let create_job = CreateJob {
+        company_id: company.id,
+        title: ingest_job.title.clone(),
+        url: ingest_job.url.clone(),
+        location: ingest_job.location.clone(),
+        remote_type: ingest_job.remote_type.clone(),
+        salary_min: ingest_job.salary_min,
+        salary_max: ingest_job.salary_max,
+        salary_currency: ingest_job.salary_currency.clone(),
+        description: ingest_job.description.clone(),
+        requirements: None,
+        source: ingest_job.source.clone(),
+        source_id: Some(ingest_job.source_id.clone()),
+        expires_at: None,
+        raw_data: ingest_job.raw_data.clone(),
+    };
+
+    let outcome = Job::upsert(&mut *tx, create_job).await?;
+
+    let event = if outcome.was_inserted {
+        Some((event_type::JOB_DISCOVERED, None))
+    } else {
+        outcome
+            .change_summary
+            .clone()
+            .map(|summary| (event_type::JOB_UPDATED, Some(summary)))
+    };
+
+    if let Some((event_type, description)) = event {
+        Event::create(
+            &mut *tx,
+            CreateEvent {
+                application_id: None,
+                job_id: Some(outcome.job.id),
+                event_type: event_type.to_string(),
+                description,
+                occurred_at: None,
+            },
+        )
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(outcome.was_inserted)
+}
@@ -1,15 +1,42 @@
 use axum::extract::{Path, State};
 use axum::Json;
+use serde::Serialize;
 use sqlx::PgPool;
 
 use crate::error::AppError;
 use crate::models::collector::{Collector, UpdateCollector};
+use crate::models::collector_run::{CollectorRun, RunKind};
 
 pub async fn list(State(pool): State<PgPool>) -> Result<Json<Vec<Collector>>, AppError> {
     let collectors = Collector::list(&pool).await?;
     Ok(Json(collectors))
 }
 
+/// A collector plus its most recent run, so a client can see the typed
+/// `CollectorRunStatus` (e.g. still `running`, or `failed` with an error)
+/// without separately triggering a new run.
+#[derive(Debug, Serialize)]
+pub struct CollectorDetail {
+    #[serde(flatten)]
+    pub collector: Collector,
+    pub latest_run: Option<CollectorRun>,
+}
+
+pub async fn get(
+    State(pool): State<PgPool>,
+    Path(name): Path<String>,
+) -> Result<Json<CollectorDetail>, AppError> {
+    let collector = Collector::get_by_name(&pool, &name).await?;
+    let latest_run = CollectorRun::recent(&pool, Some(&name), 1)
+        .await?
+        .into_iter()
+        .next();
+    Ok(Json(CollectorDetail {
+        collector,
+        latest_run,
+    }))
+}
+
 pub async fn update(
     State(pool): State<PgPool>,
     Path(name): Path<String>,
@@ -19,10 +46,22 @@ pub async fn update(
     Ok(Json(collector))
 }
 
+/// POST /api/v1/collectors/{name}/run
+///
+/// Enqueues a durable run for the named collector instead of executing it
+/// inline. A `collect` worker process (see `jobtracker collect --collector`)
+/// claims the pending row via `CollectorRun::claim_next` and does the actual
+/// work, but the `serve` process also runs `collectors::runner::run_queue`
+/// unconditionally, which drains it via `CollectorRun::claim_next_any` even
+/// with no dedicated worker running - so this handler can return as soon as
+/// the row is written either way.
+///
+/// Rejects the request with `BadRequest` if the collector is disabled or
+/// already `queued`/`running`, rather than silently re-recording a run.
 pub async fn trigger_run(
     State(pool): State<PgPool>,
     Path(name): Path<String>,
-) -> Result<Json<serde_json::Value>, AppError> {
+) -> Result<Json<CollectorRun>, AppError> {
     let collector = Collector::get_by_name(&pool, &name).await?;
     if !collector.enabled {
         return Err(AppError::BadRequest(format!(
@@ -31,12 +70,7 @@ pub async fn trigger_run(
         )));
     }
 
-    // For now, just record that it was triggered. Actual collection logic
-    // will be implemented in Phase 3 (collectors module).
-    Collector::record_run(&pool, &name, None).await?;
-
-    Ok(Json(serde_json::json!({
-        "status": "triggered",
-        "collector": name,
-    })))
+    Collector::mark_queued(&pool, &name).await?;
+    let run = CollectorRun::enqueue(&pool, &name, RunKind::Manual).await?;
+    Ok(Json(run))
 }
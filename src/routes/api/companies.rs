@@ -2,8 +2,9 @@ use axum::Json;
 use axum::extract::{Path, State};
 use sqlx::PgPool;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
+use crate::collectors::runner;
 use crate::error::AppError;
 use crate::models::company::{Company, CreateCompany, UpdateCompany};
 
@@ -12,6 +13,15 @@ pub struct FindOrCreateRequest {
     pub name: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct CompanyCollectResponse {
+    pub run_id: i32,
+    pub found: i32,
+    pub new: i32,
+    pub updated: i32,
+    pub delisted: i32,
+}
+
 pub async fn list(State(pool): State<PgPool>) -> Result<Json<Vec<Company>>, AppError> {
     let companies = Company::list(&pool).await?;
     Ok(Json(companies))
@@ -49,3 +59,28 @@ pub async fn update(
     let company = Company::update(&pool, id, input).await?;
     Ok(Json(company))
 }
+
+/// POST /api/v1/companies/{id}/collect
+///
+/// Scrapes this company's public job board on demand via its
+/// `ats_platform` collector and `careers_url`, synchronously (unlike
+/// `/collectors/{name}/run`, which just enqueues a durable run for a
+/// background worker to pick up - company-scoped collects aren't queued;
+/// the scheduler's periodic ATS sweep calls the same `collect_company`
+/// directly). Fails with `BadRequest` if the company has
+/// no `ats_platform`/`careers_url` set, or no collector is registered for
+/// its platform.
+pub async fn collect(
+    State(pool): State<PgPool>,
+    Path(id): Path<i32>,
+) -> Result<Json<CompanyCollectResponse>, AppError> {
+    let company = Company::get(&pool, id).await?;
+    let result = runner::collect_company(&pool, &company).await?;
+    Ok(Json(CompanyCollectResponse {
+        run_id: result.run.id,
+        found: result.found,
+        new: result.new,
+        updated: result.updated,
+        delisted: result.delisted,
+    }))
+}
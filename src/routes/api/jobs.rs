@@ -3,18 +3,20 @@ use axum::extract::{Path, Query, State};
 use sqlx::PgPool;
 
 use crate::error::AppError;
+use crate::models::event::{CreateEvent, Event, event_type};
 use crate::models::job::{CreateJob, Job, JobFilters, UpdateJob};
+use crate::poll_timer::WithPollTimer;
 
 pub async fn list(
     State(pool): State<PgPool>,
     Query(filters): Query<JobFilters>,
 ) -> Result<Json<Vec<Job>>, AppError> {
-    let jobs = Job::list(&pool, &filters).await?;
+    let jobs = Job::list(&pool, &filters).with_poll_timer("Job::list").await?;
     Ok(Json(jobs))
 }
 
 pub async fn get(State(pool): State<PgPool>, Path(id): Path<i32>) -> Result<Json<Job>, AppError> {
-    let job = Job::get(&pool, id).await?;
+    let job = Job::get(&pool, id).with_poll_timer("Job::get").await?;
     Ok(Json(job))
 }
 
@@ -39,13 +41,43 @@ pub async fn upsert(
     State(pool): State<PgPool>,
     Json(input): Json<CreateJob>,
 ) -> Result<Json<serde_json::Value>, AppError> {
-    let (job, was_inserted) = Job::upsert(&pool, input).await?;
+    let outcome = Job::upsert(&pool, input).await?;
+    emit_upsert_event(&pool, &outcome).await;
     Ok(Json(serde_json::json!({
-        "job": job,
-        "was_inserted": was_inserted,
+        "job": outcome.job,
+        "was_inserted": outcome.was_inserted,
     })))
 }
 
+/// Write a `job_discovered`/`job_updated` event for an upsert outcome.
+/// Best-effort: a logging failure shouldn't fail the request that already
+/// succeeded at its actual job.
+async fn emit_upsert_event(pool: &PgPool, outcome: &crate::models::job::JobUpsertOutcome) {
+    let (event_type, description) = if outcome.was_inserted {
+        (event_type::JOB_DISCOVERED, None)
+    } else if let Some(summary) = &outcome.change_summary {
+        (event_type::JOB_UPDATED, Some(summary.clone()))
+    } else {
+        return;
+    };
+
+    let result = Event::create(
+        pool,
+        CreateEvent {
+            application_id: None,
+            job_id: Some(outcome.job.id),
+            event_type: event_type.to_string(),
+            description,
+            occurred_at: None,
+        },
+    )
+    .await;
+
+    if let Err(e) = result {
+        tracing::warn!("Failed to record {event_type} event for job {}: {e}", outcome.job.id);
+    }
+}
+
 pub async fn delete(
     State(pool): State<PgPool>,
     Path(id): Path<i32>,
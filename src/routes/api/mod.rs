@@ -38,11 +38,12 @@ pub fn router(pool: PgPool) -> Router {
             "/companies/{id}",
             get(companies::get).put(companies::update),
         )
+        .route("/companies/{id}/collect", post(companies::collect))
         // Events
         .route("/events", get(events::list).post(events::create))
         // Collectors
         .route("/collectors", get(collectors::list))
-        .route("/collectors/{name}", put(collectors::update))
+        .route("/collectors/{name}", get(collectors::get).put(collectors::update))
         .route("/collectors/{name}/run", post(collectors::trigger_run))
         // Collector ingest (batch API for external collectors)
         .route("/collect/ingest", post(collect::ingest))
@@ -1,3 +1,6 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
 use askama::Template;
 use axum::Form;
 use axum::extract::{Path, State};
@@ -8,13 +11,20 @@ use sqlx::PgPool;
 use crate::auth::{generate_token, hash_token};
 use crate::error::AppError;
 use crate::models::collector::Collector;
+use crate::models::collector_run::CollectorRun;
 use crate::routes::api::tokens::TokenInfo;
 
+/// How long a collector may sit `running` before its admin-page row gets a
+/// "stalled" badge. Shorter than `reap_timed_out`'s threshold so operators
+/// get a heads-up before a run is actually reaped.
+const STALLED_THRESHOLD: Duration = Duration::from_secs(300);
+
 #[derive(Template)]
 #[template(path = "admin/index.html")]
 struct AdminTemplate {
     tokens: Vec<TokenInfo>,
     collectors: Vec<Collector>,
+    stalled_collectors: HashSet<String>,
 }
 
 pub async fn index(State(pool): State<PgPool>) -> Result<Html<String>, AppError> {
@@ -26,7 +36,21 @@ pub async fn index(State(pool): State<PgPool>) -> Result<Html<String>, AppError>
 
     let collectors = Collector::list(&pool).await?;
 
-    let tmpl = AdminTemplate { tokens, collectors };
+    let mut stalled_collectors = HashSet::new();
+    for collector in &collectors {
+        if CollectorRun::running_longer_than(&pool, &collector.name, STALLED_THRESHOLD)
+            .await?
+            .is_some()
+        {
+            stalled_collectors.insert(collector.name.clone());
+        }
+    }
+
+    let tmpl = AdminTemplate {
+        tokens,
+        collectors,
+        stalled_collectors,
+    };
     Ok(Html(
         tmpl.render()
             .map_err(|e| AppError::Internal(e.to_string()))?,
@@ -6,7 +6,7 @@ use serde::Deserialize;
 use sqlx::PgPool;
 
 use crate::error::{AppError, HtmlError};
-use crate::models::application::{Application, ApplicationFilters, UpdateApplication};
+use crate::models::application::{Application, ApplicationFilters, ApplicationStatus, UpdateApplication};
 use crate::models::event::{CreateEvent, Event};
 use crate::models::job::Job;
 
@@ -42,15 +42,7 @@ struct TimelinePartial {
 }
 
 fn all_statuses() -> Vec<String> {
-    vec![
-        "draft".into(),
-        "applied".into(),
-        "interviewing".into(),
-        "rejected".into(),
-        "offer".into(),
-        "accepted".into(),
-        "withdrawn".into(),
-    ]
+    ApplicationStatus::ALL.iter().map(|s| s.to_string()).collect()
 }
 
 #[derive(Debug, Deserialize)]
@@ -62,9 +54,13 @@ pub async fn list(
     State(pool): State<PgPool>,
     Query(query): Query<AppListQuery>,
 ) -> Result<Html<String>, HtmlError> {
-    let filters = ApplicationFilters {
-        status: query.status.clone().filter(|s| !s.is_empty()),
-    };
+    let status = query
+        .status
+        .clone()
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<ApplicationStatus>())
+        .transpose()?;
+    let filters = ApplicationFilters { status };
     let apps = Application::list(&pool, &filters).await?;
 
     // Enrich with job/company info
@@ -162,11 +158,16 @@ pub async fn update(
     Path(id): Path<i32>,
     Form(input): Form<UpdateAppForm>,
 ) -> Result<Redirect, HtmlError> {
+    let status = input
+        .status
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<ApplicationStatus>())
+        .transpose()?;
     Application::update(
         &pool,
         id,
         UpdateApplication {
-            status: input.status.filter(|s| !s.is_empty()),
+            status,
             cv_variant: input.cv_variant.filter(|s| !s.is_empty()),
             applied_at: None,
             response_at: None,
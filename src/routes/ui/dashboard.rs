@@ -4,6 +4,7 @@ use axum::response::Html;
 use sqlx::PgPool;
 
 use crate::error::AppError;
+use crate::models::application::ApplicationStatus;
 use crate::models::event::Event;
 use crate::models::job::Job;
 
@@ -14,7 +15,7 @@ struct DashboardTemplate {
     app_count: i64,
     interviewing_count: i64,
     offer_count: i64,
-    status_counts: Vec<(String, i64)>,
+    status_counts: Vec<(ApplicationStatus, i64)>,
     recent_jobs: Vec<Job>,
     recent_events: Vec<Event>,
 }
@@ -29,12 +30,12 @@ pub async fn index(State(pool): State<PgPool>) -> Result<Html<String>, AppError>
     let app_count: i64 = status_counts.iter().map(|(_, c)| c).sum();
     let interviewing_count = status_counts
         .iter()
-        .find(|(s, _)| s == "interviewing")
+        .find(|(s, _)| *s == ApplicationStatus::Interviewing)
         .map(|(_, c)| *c)
         .unwrap_or(0);
     let offer_count = status_counts
         .iter()
-        .filter(|(s, _)| s == "offer" || s == "accepted")
+        .filter(|(s, _)| *s == ApplicationStatus::Offer || *s == ApplicationStatus::Accepted)
         .map(|(_, c)| c)
         .sum();
 
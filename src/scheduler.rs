@@ -0,0 +1,105 @@
+use std::time::Duration;
+
+use sqlx::PgPool;
+
+use crate::collectors::get_collector;
+use crate::collectors::runner::{DEFAULT_HEARTBEAT_INTERVAL_SECS, collect_company, process_run};
+use crate::models::collector::Collector;
+use crate::models::collector_run::{CollectorRun, RunKind};
+use crate::models::company::Company;
+
+/// Background scheduler: on each tick, claims any collector whose
+/// `next_run_at` has elapsed (via `SELECT ... FOR UPDATE SKIP LOCKED`, so
+/// running more than one server instance doesn't double-run the same
+/// collector), and drives it through the same run lifecycle as a
+/// manually-triggered or CLI-driven run. Spawned from `main` alongside
+/// `axum::serve` so the crate collects jobs on its own schedule instead of
+/// only reacting to external pushes to `/api/v1/collect/ingest`.
+///
+/// Each tick also claims any company due for its own ATS sweep (see
+/// `Company::claim_due_for_ats_collect`), so boards configured via
+/// `ats_platform`/`careers_url` get monitored on a schedule the same way,
+/// instead of only reacting to an on-demand `/companies/{id}/collect`.
+pub async fn run(pool: PgPool, poll_interval: u64) {
+    tracing::info!("Scheduler started, polling every {poll_interval}s");
+
+    loop {
+        loop {
+            match Collector::claim_due(&pool).await {
+                Ok(Some(collector)) => spawn_run(pool.clone(), collector),
+                Ok(None) => break,
+                Err(e) => {
+                    tracing::error!("Scheduler failed to claim due collectors: {e}");
+                    break;
+                }
+            }
+        }
+
+        loop {
+            match Company::claim_due_for_ats_collect(&pool).await {
+                Ok(Some(company)) => spawn_company_collect(pool.clone(), company),
+                Ok(None) => break,
+                Err(e) => {
+                    tracing::error!("Scheduler failed to claim due ATS companies: {e}");
+                    break;
+                }
+            }
+        }
+
+        tokio::time::sleep(Duration::from_secs(poll_interval)).await;
+    }
+}
+
+/// Run one claimed collector to completion in its own task, so a slow
+/// collector doesn't delay the scheduler from claiming others that are due.
+fn spawn_run(pool: PgPool, collector: Collector) {
+    tokio::spawn(async move {
+        let Some(collector_impl) = get_collector(&collector.name) else {
+            tracing::error!("Scheduler claimed unknown collector '{}'", collector.name);
+            let _ = Collector::mark_failed(
+                &pool,
+                &collector.name,
+                "No JobCollector implementation registered for this collector",
+                true,
+            )
+            .await;
+            return;
+        };
+
+        let run = match CollectorRun::enqueue(&pool, &collector.name, RunKind::Scheduled).await {
+            Ok(run) => run,
+            Err(e) => {
+                tracing::error!(
+                    "Scheduler failed to enqueue run for '{}': {e}",
+                    collector.name
+                );
+                return;
+            }
+        };
+
+        let run = match CollectorRun::claim(&pool, run.id).await {
+            Ok(run) => run,
+            Err(e) => {
+                tracing::error!("Scheduler failed to claim run {}: {e}", run.id);
+                return;
+            }
+        };
+
+        process_run(&pool, &*collector_impl, &run, DEFAULT_HEARTBEAT_INTERVAL_SECS).await;
+    });
+}
+
+/// Run one claimed company's ATS collect to completion in its own task, so
+/// a slow board fetch doesn't delay the scheduler from claiming others
+/// that are due.
+fn spawn_company_collect(pool: PgPool, company: Company) {
+    tokio::spawn(async move {
+        if let Err(e) = collect_company(&pool, &company).await {
+            tracing::error!(
+                "Scheduled ATS collect for company {} ('{}') failed: {e}",
+                company.id,
+                company.name
+            );
+        }
+    });
+}